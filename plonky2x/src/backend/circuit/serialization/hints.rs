@@ -15,6 +15,8 @@ use curta::plonky2::cubic::arithmetic_gate::ArithmeticCubicGenerator;
 use curta::plonky2::cubic::mul_gate::MulCubicGenerator;
 use curta::plonky2::stark::generator::simple::SimpleStarkWitnessGenerator;
 use plonky2::field::extension::Extendable;
+use plonky2::field::secp256k1_base::Secp256K1Base;
+use plonky2::field::secp256k1_scalar::Secp256K1Scalar;
 use plonky2::gadgets::arithmetic::EqualityGenerator;
 use plonky2::gadgets::arithmetic_extension::QuotientGeneratorExtension;
 use plonky2::gadgets::range_check::LowHighGenerator;
@@ -47,6 +49,7 @@ use super::PlonkParameters;
 use crate::frontend::builder::watch::WatchGenerator;
 use crate::frontend::curta::hash::sha::sha256::hint::Sha256ProofHint;
 use crate::frontend::ecc::ed25519::field::ed25519_base::Ed25519Base;
+use crate::frontend::ecc::ed25519::field::ed25519_scalar::Ed25519Scalar;
 use crate::frontend::eth::beacon::generators::{
     BeaconAllWithdrawalsHint, BeaconBalanceBatchWitnessHint, BeaconBalanceGenerator,
     BeaconBalanceWitnessHint, BeaconBalancesGenerator, BeaconExecutionPayloadHint,
@@ -72,10 +75,14 @@ use crate::frontend::hint::asynchronous::serializer::AsyncHintSerializer;
 use crate::frontend::hint::simple::hint::Hint;
 use crate::frontend::hint::simple::serializer::SimpleHintSerializer;
 use crate::frontend::hint::synchronous::Async;
-use crate::frontend::num::biguint::BigUintDivRemGenerator;
+use crate::frontend::num::biguint::{
+    BigUintDivRemGenerator, BigUintGcdGenerator, BigUintModInverseGenerator,
+};
 use crate::frontend::num::nonnative::nonnative::{
-    NonNativeAdditionGenerator, NonNativeInverseGenerator, NonNativeMultipleAddsGenerator,
-    NonNativeMultiplicationGenerator, NonNativeSubtractionGenerator,
+    NonNativeAdditionGenerator, NonNativeCanonicalGenerator, NonNativeCrtGenerator,
+    NonNativeInverseGenerator, NonNativeMultipleAddsGenerator, NonNativeMultipleSubsGenerator,
+    NonNativeMultiplicationGenerator, NonNativeSmallMultipleGenerator, NonNativeSqrtGenerator,
+    NonNativeSubtractionGenerator, NonNativeSumGenerator,
 };
 use crate::frontend::num::u32::gates::add_many_u32::U32AddManyGenerator;
 use crate::frontend::num::u32::gates::arithmetic_u32::U32ArithmeticGenerator;
@@ -180,6 +187,23 @@ macro_rules! register_watch_generator {
     };
 }
 
+/// Registers each nonnative generator for every field it is used with. Generator ids include the
+/// field modulus, so each monomorphization needs its own entry.
+macro_rules! register_nonnative_generators {
+    ($registry:ident, $f:ty, $d:ty, $($generator:ident),*) => {
+        $(
+            let id = $generator::<$f, $d, Ed25519Base>::default().id();
+            $registry.register_simple::<$generator<$f, $d, Ed25519Base>>(id);
+            let id = $generator::<$f, $d, Ed25519Scalar>::default().id();
+            $registry.register_simple::<$generator<$f, $d, Ed25519Scalar>>(id);
+            let id = $generator::<$f, $d, Secp256K1Base>::default().id();
+            $registry.register_simple::<$generator<$f, $d, Secp256K1Base>>(id);
+            let id = $generator::<$f, $d, Secp256K1Scalar>::default().id();
+            $registry.register_simple::<$generator<$f, $d, Secp256K1Scalar>>(id);
+        )*
+    };
+}
+
 macro_rules! register_powers_of_two {
     ($r:ident, $hint:ident) => {
         $r.register_hint::<$hint<2>>();
@@ -345,6 +369,14 @@ where
         let big_uint_div_rem_generator_id = BigUintDivRemGenerator::<L::Field, D>::id();
         r.register_simple::<BigUintDivRemGenerator<L::Field, D>>(big_uint_div_rem_generator_id);
 
+        let big_uint_gcd_generator_id = BigUintGcdGenerator::<L::Field, D>::id();
+        r.register_simple::<BigUintGcdGenerator<L::Field, D>>(big_uint_gcd_generator_id);
+
+        let big_uint_mod_inverse_generator_id = BigUintModInverseGenerator::<L::Field, D>::id();
+        r.register_simple::<BigUintModInverseGenerator<L::Field, D>>(
+            big_uint_mod_inverse_generator_id,
+        );
+
         let u32_arithmetic_generator_id = U32ArithmeticGenerator::<L::Field, D>::id();
         r.register_simple::<U32ArithmeticGenerator<L::Field, D>>(u32_arithmetic_generator_id);
 
@@ -410,20 +442,22 @@ where
         register_powers_of_two!(r, BeaconValidatorBatchHint);
         register_powers_of_two!(r, BeaconPartialValidatorsHint);
         register_powers_of_two!(r, CompressedBeaconValidatorBatchHint);
-        let id = NonNativeAdditionGenerator::<L::Field, D, Ed25519Base>::default().id();
-        r.register_simple::<NonNativeAdditionGenerator<L::Field, D, Ed25519Base>>(id);
-
-        let id = NonNativeInverseGenerator::<L::Field, D, Ed25519Base>::default().id();
-        r.register_simple::<NonNativeInverseGenerator<L::Field, D, Ed25519Base>>(id);
-
-        let id = NonNativeMultipleAddsGenerator::<L::Field, D, Ed25519Base>::default().id();
-        r.register_simple::<NonNativeMultipleAddsGenerator<L::Field, D, Ed25519Base>>(id);
-
-        let id = NonNativeMultiplicationGenerator::<L::Field, D, Ed25519Base>::default().id();
-        r.register_simple::<NonNativeMultiplicationGenerator<L::Field, D, Ed25519Base>>(id);
-
-        let id = NonNativeSubtractionGenerator::<L::Field, D, Ed25519Base>::default().id();
-        r.register_simple::<NonNativeSubtractionGenerator<L::Field, D, Ed25519Base>>(id);
+        register_nonnative_generators!(
+            r,
+            L::Field,
+            D,
+            NonNativeAdditionGenerator,
+            NonNativeInverseGenerator,
+            NonNativeMultipleAddsGenerator,
+            NonNativeMultipleSubsGenerator,
+            NonNativeMultiplicationGenerator,
+            NonNativeSubtractionGenerator,
+            NonNativeSqrtGenerator,
+            NonNativeCrtGenerator,
+            NonNativeSmallMultipleGenerator,
+            NonNativeSumGenerator,
+            NonNativeCanonicalGenerator
+        );
 
         let id =
             SimpleScalarMulEd25519Generator::<L::Field, L::CubicParams, L::CurtaConfig, D>::id();
@@ -76,6 +76,15 @@ impl<C: Curve> CircuitVariable for AffinePointTarget<C> {
     }
 }
 
+/// A Target representing a point on the curve `C` in Jacobian coordinates `(X, Y, Z)`, which
+/// corresponds to the affine point `(X / Z^2, Y / Z^3)`.
+#[derive(Clone, Debug, Default)]
+pub struct JacobianPointTarget<C: Curve> {
+    pub x: NonNativeTarget<C::BaseField>,
+    pub y: NonNativeTarget<C::BaseField>,
+    pub z: NonNativeTarget<C::BaseField>,
+}
+
 pub struct CompressedPointTarget {
     pub bit_targets: [BoolTarget; 256],
 }
@@ -131,6 +140,11 @@ pub trait CircuitBuilderCurve<F: RichField + Extendable<D>, const D: usize> {
         a: &AffinePointTarget<C>,
         b: &AffinePointTarget<C>,
     ) -> BoolTarget;
+
+    fn affine_to_jacobian<C: Curve>(&mut self, p: &AffinePointTarget<C>) -> JacobianPointTarget<C>;
+
+    /// Convert a Jacobian point back to affine coordinates. The point is assumed to have `Z != 0`.
+    fn jacobian_to_affine<C: Curve>(&mut self, p: &JacobianPointTarget<C>) -> AffinePointTarget<C>;
 }
 
 impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderCurve<F, D>
@@ -306,6 +320,25 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderCurve<F, D>
 
         self.and(x_equal, y_equal)
     }
+
+    fn affine_to_jacobian<C: Curve>(&mut self, p: &AffinePointTarget<C>) -> JacobianPointTarget<C> {
+        JacobianPointTarget {
+            x: p.x.clone(),
+            y: p.y.clone(),
+            z: self.constant_nonnative(C::BaseField::ONE),
+        }
+    }
+
+    fn jacobian_to_affine<C: Curve>(&mut self, p: &JacobianPointTarget<C>) -> AffinePointTarget<C> {
+        let z_inv = self.inv_nonnative(&p.z);
+        let z_inv_squared = self.mul_nonnative(&z_inv, &z_inv);
+        let z_inv_cubed = self.mul_nonnative(&z_inv_squared, &z_inv);
+
+        let x = self.mul_nonnative(&p.x, &z_inv_squared);
+        let y = self.mul_nonnative(&p.y, &z_inv_cubed);
+
+        AffinePointTarget { x, y }
+    }
 }
 
 pub trait WitnessAffinePoint<F: PrimeField64>: Witness<F> {
@@ -347,8 +380,8 @@ pub trait WriteAffinePoint {
 impl WriteAffinePoint for Vec<u8> {
     #[inline]
     fn write_target_affine_point<C: Curve>(&mut self, point: AffinePointTarget<C>) -> IoResult<()> {
-        self.write_target_nonnative(point.x)?;
-        self.write_target_nonnative(point.y)
+        self.write_target_nonnative(&point.x)?;
+        self.write_target_nonnative(&point.y)
     }
 }
 
@@ -368,7 +401,7 @@ impl ReadAffinePoint for Buffer<'_> {
 #[cfg(test)]
 mod tests {
 
-    use plonky2::field::types::{Field, Sample};
+    use plonky2::field::types::{Field, PrimeField, Sample};
     use plonky2::iop::witness::{PartialWitness, WitnessWrite};
     use plonky2::plonk::circuit_builder::CircuitBuilder as BaseCircuitBuilder;
     use plonky2::plonk::circuit_data::CircuitConfig;
@@ -378,9 +411,10 @@ mod tests {
     use crate::frontend::ecc::ed25519::curve::ed25519::Ed25519;
     use crate::frontend::ecc::ed25519::field::ed25519_base::Ed25519Base;
     use crate::frontend::ecc::ed25519::field::ed25519_scalar::Ed25519Scalar;
-    use crate::frontend::ecc::ed25519::gadgets::curve::CircuitBuilderCurve;
+    use crate::frontend::ecc::ed25519::gadgets::curve::{CircuitBuilderCurve, JacobianPointTarget};
     use crate::frontend::hash::bit_operations::util::biguint_to_bits_target;
-    use crate::frontend::num::biguint::CircuitBuilderBiguint;
+    use crate::frontend::num::biguint::{CircuitBuilderBiguint, WitnessBigUint};
+    use crate::frontend::num::nonnative::nonnative::CircuitBuilderNonNative;
 
     #[test]
     #[cfg_attr(feature = "ci", ignore)]
@@ -544,4 +578,46 @@ mod tests {
 
         data.verify(proof).unwrap();
     }
+
+    #[test]
+    fn test_affine_jacobian_round_trip() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let p = (CurveScalar(Ed25519Scalar::rand()) * Ed25519::GENERATOR_PROJECTIVE).to_affine();
+        let p_target = builder.constant_affine_point(p);
+
+        let p_jacobian = builder.affine_to_jacobian(&p_target);
+        let p_round_trip = builder.jacobian_to_affine(&p_jacobian);
+        builder.connect_affine_point(&p_target, &p_round_trip);
+
+        // A Jacobian representation of the same point with a nontrivial `Z`.
+        let z = Ed25519Base::rand();
+        let z_squared = z * z;
+        let scaled = JacobianPointTarget::<Ed25519> {
+            x: builder.add_virtual_nonnative_target(),
+            y: builder.add_virtual_nonnative_target(),
+            z: builder.add_virtual_nonnative_target(),
+        };
+        pw.set_biguint_target(&scaled.x.value, &(p.x * z_squared).to_canonical_biguint());
+        pw.set_biguint_target(
+            &scaled.y.value,
+            &(p.y * z_squared * z).to_canonical_biguint(),
+        );
+        pw.set_biguint_target(&scaled.z.value, &z.to_canonical_biguint());
+
+        let scaled_affine = builder.jacobian_to_affine(&scaled);
+        builder.connect_affine_point(&p_target, &scaled_affine);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+
+        data.verify(proof).unwrap();
+    }
 }
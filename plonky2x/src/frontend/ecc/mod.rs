@@ -1 +1,2 @@
 pub mod ed25519;
+pub mod secp256k1;
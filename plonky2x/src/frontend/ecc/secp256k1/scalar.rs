@@ -0,0 +1,140 @@
+use num::{BigUint, One};
+use plonky2::field::extension::Extendable;
+use plonky2::field::secp256k1_scalar::Secp256K1Scalar;
+use plonky2::field::types::Field;
+use plonky2::hash::hash_types::RichField;
+use plonky2::plonk::circuit_builder::CircuitBuilder as BaseCircuitBuilder;
+
+use crate::frontend::num::biguint::CircuitBuilderBiguint;
+use crate::frontend::num::nonnative::nonnative::{CircuitBuilderNonNative, NonNativeTarget};
+
+/// An element of the secp256k1 scalar field, i.e. an integer modulo the curve order `n`.
+pub type Secp256K1ScalarTarget = NonNativeTarget<Secp256K1Scalar>;
+
+pub trait CircuitBuilderSecp256K1Scalar<F: RichField + Extendable<D>, const D: usize> {
+    /// Returns `a * b % n`.
+    fn scalar_field_mul(
+        &mut self,
+        a: &Secp256K1ScalarTarget,
+        b: &Secp256K1ScalarTarget,
+    ) -> Secp256K1ScalarTarget;
+
+    /// Returns `x^{-1} % n`. The input is assumed to be nonzero.
+    fn scalar_field_inv(&mut self, x: &Secp256K1ScalarTarget) -> Secp256K1ScalarTarget;
+
+    /// Asserts that the ECDSA signature components `r` and `s` both lie in `[1, n - 1]`.
+    fn ecdsa_verify_precheck(&mut self, r: &Secp256K1ScalarTarget, s: &Secp256K1ScalarTarget);
+}
+
+impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderSecp256K1Scalar<F, D>
+    for BaseCircuitBuilder<F, D>
+{
+    fn scalar_field_mul(
+        &mut self,
+        a: &Secp256K1ScalarTarget,
+        b: &Secp256K1ScalarTarget,
+    ) -> Secp256K1ScalarTarget {
+        self.mul_nonnative(a, b)
+    }
+
+    fn scalar_field_inv(&mut self, x: &Secp256K1ScalarTarget) -> Secp256K1ScalarTarget {
+        self.inv_nonnative(x)
+    }
+
+    fn ecdsa_verify_precheck(&mut self, r: &Secp256K1ScalarTarget, s: &Secp256K1ScalarTarget) {
+        let zero = self.zero_biguint();
+        let n_minus_one = self.constant_biguint(&(Secp256K1Scalar::order() - BigUint::one()));
+
+        for x in [r, s] {
+            let is_zero = self.is_equal_biguint(&x.value, &zero);
+            self.assert_zero(is_zero.target);
+
+            let is_reduced = self.cmp_biguint(&x.value, &n_minus_one);
+            self.assert_one(is_reduced.target);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use plonky2::field::types::Sample;
+    use plonky2::iop::witness::PartialWitness;
+    use plonky2::plonk::circuit_data::CircuitConfig;
+    use plonky2::plonk::config::{GenericConfig, PoseidonGoldilocksConfig};
+
+    use super::*;
+    use crate::frontend::num::biguint::WitnessBigUint;
+
+    #[test]
+    fn test_scalar_field_mul_and_inv() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let a = Secp256K1Scalar::rand();
+        let b = Secp256K1Scalar::rand();
+
+        let a_target = builder.constant_nonnative(a);
+        let b_target = builder.constant_nonnative(b);
+        let product = builder.scalar_field_mul(&a_target, &b_target);
+        let expected_product = builder.constant_nonnative(a * b);
+        builder.connect_nonnative(&product, &expected_product);
+
+        let a_inv = builder.scalar_field_inv(&a_target);
+        let expected_a_inv = builder.constant_nonnative(a.inverse());
+        builder.connect_nonnative(&a_inv, &expected_a_inv);
+
+        builder.ecdsa_verify_precheck(&a_target, &b_target);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ecdsa_verify_precheck_unreduced() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let r = builder.add_virtual_nonnative_target::<Secp256K1Scalar>();
+        let s = builder.constant_nonnative(Secp256K1Scalar::rand());
+        pw.set_biguint_target(&r.value, &Secp256K1Scalar::order());
+
+        builder.ecdsa_verify_precheck(&r, &s);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ecdsa_verify_precheck_zero() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let r = builder.constant_nonnative(Secp256K1Scalar::rand());
+        let s = builder.zero_nonnative();
+
+        builder.ecdsa_verify_precheck(&r, &s);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+}
@@ -1,13 +1,15 @@
 use core::marker::PhantomData;
 
 use itertools::Itertools;
-use num::{BigUint, Integer, Zero};
+use num::bigint::Sign;
+use num::integer::ExtendedGcd;
+use num::{BigInt, BigUint, Integer, One, Zero};
 use plonky2::field::extension::Extendable;
 use plonky2::field::types::{PrimeField, PrimeField64};
 use plonky2::hash::hash_types::RichField;
 use plonky2::iop::generator::{GeneratedValues, SimpleGenerator};
 use plonky2::iop::target::{BoolTarget, Target};
-use plonky2::iop::witness::{PartitionWitness, Witness};
+use plonky2::iop::witness::{PartitionWitness, Witness, WitnessWrite};
 use plonky2::plonk::circuit_builder::CircuitBuilder;
 use plonky2::plonk::circuit_data::CommonCircuitData;
 use plonky2::util::serialization::{Buffer, IoResult, Read, Write};
@@ -15,6 +17,7 @@ use plonky2::util::serialization::{Buffer, IoResult, Read, Write};
 use super::u32::serialization::{ReadU32, WriteU32};
 use crate::frontend::num::u32::gadgets::arithmetic_u32::{CircuitBuilderU32, U32Target};
 use crate::frontend::num::u32::gadgets::multiple_comparison::list_le_u32_circuit;
+use crate::frontend::num::u32::gadgets::range_check::range_check_u32_circuit;
 use crate::frontend::num::u32::witness::{GeneratedValuesU32, WitnessU32};
 
 #[derive(Clone, Debug, Default)]
@@ -59,6 +62,9 @@ pub trait CircuitBuilderBiguint<F: RichField + Extendable<D>, const D: usize> {
 
     fn mul_biguint_by_bool(&mut self, a: &BigUintTarget, b: BoolTarget) -> BigUintTarget;
 
+    /// Multiply a `BigUintTarget` by a constant, skipping the zero limbs of the constant.
+    fn mul_biguint_by_constant(&mut self, a: &BigUintTarget, c: &BigUint) -> BigUintTarget;
+
     /// Returns x * y + z. This is no more efficient than mul-then-add; it's purely for convenience (only need to call one CircuitBuilder function).
     fn mul_add_biguint(
         &mut self,
@@ -98,7 +104,33 @@ pub trait CircuitBuilderBiguint<F: RichField + Extendable<D>, const D: usize> {
         v: Vec<&BigUintTarget>,
     ) -> BigUintTarget;
 
+    /// Returns whether `a == b`, treating missing high limbs of the shorter operand as zero.
     fn is_equal_biguint(&mut self, a: &BigUintTarget, b: &BigUintTarget) -> BoolTarget;
+
+    /// Returns `base^exponent % modulus`, where the modulus need not be prime.
+    fn modpow_biguint(
+        &mut self,
+        base: &BigUintTarget,
+        exponent: &BigUintTarget,
+        modulus: &BigUintTarget,
+    ) -> BigUintTarget;
+
+    /// Returns `x << n` for a constant `n`.
+    fn shl_biguint(&mut self, x: &BigUintTarget, n: usize) -> BigUintTarget;
+
+    /// Returns `x >> n` for a constant `n`.
+    fn shr_biguint(&mut self, x: &BigUintTarget, n: usize) -> BigUintTarget;
+
+    /// Returns `gcd(a, b)`, where `gcd(0, 0) = 0`.
+    fn gcd_biguint(&mut self, a: &BigUintTarget, b: &BigUintTarget) -> BigUintTarget;
+
+    /// Returns the inverse of `a` modulo `modulus > 1` together with a flag indicating whether it
+    /// exists, i.e. whether `gcd(a, modulus) = 1`. If it does not, the returned inverse is zero.
+    fn mod_inv_biguint(
+        &mut self,
+        a: &BigUintTarget,
+        modulus: &BigUintTarget,
+    ) -> (BigUintTarget, BoolTarget);
 }
 
 impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderBiguint<F, D>
@@ -232,6 +264,43 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderBiguint<F, D>
         }
     }
 
+    fn mul_biguint_by_constant(&mut self, a: &BigUintTarget, c: &BigUint) -> BigUintTarget {
+        let c_limbs = c.to_u32_digits();
+        let total_limbs = a.limbs.len() + c_limbs.len();
+
+        let mut to_add = vec![vec![]; total_limbs];
+        for (j, &c_limb) in c_limbs.iter().enumerate() {
+            if c_limb == 0 {
+                continue;
+            }
+            let c_limb = self.constant_u32(c_limb);
+            for i in 0..a.limbs.len() {
+                let (product, carry) = self.mul_u32(a.limbs[i], c_limb);
+                to_add[i + j].push(product);
+                to_add[i + j + 1].push(carry);
+            }
+        }
+
+        let mut combined_limbs = vec![];
+        let mut carry = self.zero_u32();
+        for summands in &mut to_add {
+            // Columns below a run of zero limbs in `c` receive no partial products.
+            if summands.is_empty() {
+                combined_limbs.push(carry);
+                carry = self.zero_u32();
+                continue;
+            }
+            let (new_result, new_carry) = self.add_u32s_with_carry(summands, carry);
+            combined_limbs.push(new_result);
+            carry = new_carry;
+        }
+        combined_limbs.push(carry);
+
+        BigUintTarget {
+            limbs: combined_limbs,
+        }
+    }
+
     fn mul_biguint_by_bool(&mut self, a: &BigUintTarget, b: BoolTarget) -> BigUintTarget {
         let t = b.target;
 
@@ -272,11 +341,17 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderBiguint<F, D>
             _phantom: PhantomData,
         });
 
+        range_check_u32_circuit(self, div.limbs.clone());
+        range_check_u32_circuit(self, rem.limbs.clone());
+
         let div_b = self.mul_biguint(&div, b);
         let div_b_plus_rem = self.add_biguint(&div_b, &rem);
         self.connect_biguint(a, &div_b_plus_rem);
 
-        let cmp_rem_b = self.cmp_biguint(&rem, b);
+        // `cmp_biguint` is a non-strict comparison, so check `rem + 1 <= b` to get `rem < b`.
+        let one = self.constant_biguint(&BigUint::one());
+        let rem_plus_one = self.add_biguint(&rem, &one);
+        let cmp_rem_b = self.cmp_biguint(&rem_plus_one, b);
         self.assert_one(cmp_rem_b.target);
 
         (div, rem)
@@ -347,26 +422,205 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderBiguint<F, D>
     }
 
     fn is_equal_biguint(&mut self, a: &BigUintTarget, b: &BigUintTarget) -> BoolTarget {
+        // Each `is_equal_u32` is constrained in both directions, and so is their conjunction.
+        let num_limbs = a.num_limbs().max(b.num_limbs());
+        let zero_u32 = self.zero_u32();
         let mut ret = self._true();
-        let false_t = self._false().target;
+        for i in 0..num_limbs {
+            let a_limb = a.limbs.get(i).copied().unwrap_or(zero_u32);
+            let b_limb = b.limbs.get(i).copied().unwrap_or(zero_u32);
+            let limb_equal = self.is_equal_u32(a_limb, b_limb);
+            ret = self.and(ret, limb_equal);
+        }
 
-        let min_limbs = a.num_limbs().min(b.num_limbs());
-        for i in 0..min_limbs {
-            let limb_equal = self.is_equal_u32(a.get_limb(i), b.get_limb(i));
-            ret = BoolTarget::new_unsafe(self.select(limb_equal, ret.target, false_t));
+        ret
+    }
+
+    /// Returns `base^exponent % modulus`, where the modulus need not be prime.
+    fn modpow_biguint(
+        &mut self,
+        base: &BigUintTarget,
+        exponent: &BigUintTarget,
+        modulus: &BigUintTarget,
+    ) -> BigUintTarget {
+        let one = self.constant_biguint(&BigUint::one());
+        let mut acc = self.rem_biguint(&one, modulus);
+        let base = self.rem_biguint(base, modulus);
+        let exponent_bits = exponent
+            .limbs
+            .iter()
+            .flat_map(|&l| self.u32_to_bits_le(l))
+            .collect::<Vec<_>>();
+
+        // Square-and-multiply, scanning the exponent from its most significant bit.
+        for &bit in exponent_bits.iter().rev() {
+            let squared = self.mul_biguint(&acc, &acc);
+            acc = self.rem_biguint(&squared, modulus);
+
+            let multiplied = self.mul_biguint(&acc, &base);
+            let multiplied = self.rem_biguint(&multiplied, modulus);
+            let limbs = acc
+                .limbs
+                .iter()
+                .zip(multiplied.limbs.iter())
+                .map(|(&a, &m)| U32Target(self.select(bit, m.0, a.0)))
+                .collect();
+            acc = BigUintTarget { limbs };
         }
 
-        let zero_u32 = self.zero_u32();
-        for i in min_limbs..a.num_limbs() {
-            let is_zero = self.is_equal_u32(a.get_limb(i), zero_u32);
-            ret = BoolTarget::new_unsafe(self.select(is_zero, ret.target, false_t));
+        acc
+    }
+
+    fn shl_biguint(&mut self, x: &BigUintTarget, n: usize) -> BigUintTarget {
+        let (limb_shift, bit_shift) = (n / 32, n % 32);
+        let zero = self.zero_u32();
+        let mut limbs = vec![zero; limb_shift];
+
+        // Shifting by whole limbs needs no bit decomposition.
+        if bit_shift == 0 {
+            limbs.extend_from_slice(&x.limbs);
+            return BigUintTarget { limbs };
         }
-        for i in min_limbs..b.num_limbs() {
-            let is_zero = self.is_equal_u32(b.get_limb(i), zero_u32);
-            ret = BoolTarget::new_unsafe(self.select(is_zero, ret.target, false_t));
+
+        // Each output limb is the low bits of one input limb shifted up, plus the high bits
+        // carried over from the limb below it.
+        let base = F::from_canonical_u64(1 << bit_shift);
+        let mut carry = zero;
+        for &limb in x.limbs.iter() {
+            let bits = self.u32_to_bits_le(limb);
+            let low = self.le_sum(bits[..32 - bit_shift].iter());
+            let high = self.le_sum(bits[32 - bit_shift..].iter());
+            limbs.push(U32Target(self.mul_const_add(base, low, carry.0)));
+            carry = U32Target(high);
         }
+        limbs.push(carry);
 
-        ret
+        BigUintTarget { limbs }
+    }
+
+    fn shr_biguint(&mut self, x: &BigUintTarget, n: usize) -> BigUintTarget {
+        let (limb_shift, bit_shift) = (n / 32, n % 32);
+        if limb_shift >= x.num_limbs() {
+            return self.zero_biguint();
+        }
+        let shifted = &x.limbs[limb_shift..];
+
+        // Shifting by whole limbs needs no bit decomposition.
+        if bit_shift == 0 {
+            return BigUintTarget {
+                limbs: shifted.to_vec(),
+            };
+        }
+
+        // The bit decomposition range-checks each limb, so the dropped low bits are well-defined.
+        let parts = shifted
+            .iter()
+            .map(|&limb| {
+                let bits = self.u32_to_bits_le(limb);
+                let low = self.le_sum(bits[..bit_shift].iter());
+                let high = self.le_sum(bits[bit_shift..].iter());
+                (low, high)
+            })
+            .collect_vec();
+
+        let base = F::from_canonical_u64(1 << (32 - bit_shift));
+        let mut limbs = Vec::with_capacity(parts.len());
+        for (i, &(_, high)) in parts.iter().enumerate() {
+            let limb = match parts.get(i + 1) {
+                Some(&(next_low, _)) => self.mul_const_add(base, next_low, high),
+                None => high,
+            };
+            limbs.push(U32Target(limb));
+        }
+
+        BigUintTarget { limbs }
+    }
+
+    fn gcd_biguint(&mut self, a: &BigUintTarget, b: &BigUintTarget) -> BigUintTarget {
+        let gcd = self.add_virtual_biguint_target(a.num_limbs().max(b.num_limbs()));
+        let a_div_gcd = self.add_virtual_biguint_target(a.num_limbs());
+        let b_div_gcd = self.add_virtual_biguint_target(b.num_limbs());
+        let s = self.add_virtual_biguint_target(b.num_limbs());
+        let t = self.add_virtual_biguint_target(a.num_limbs());
+        let negative = self.add_virtual_bool_target_safe();
+
+        self.add_simple_generator(BigUintGcdGenerator::<F, D> {
+            a: a.clone(),
+            b: b.clone(),
+            gcd: gcd.clone(),
+            a_div_gcd: a_div_gcd.clone(),
+            b_div_gcd: b_div_gcd.clone(),
+            s: s.clone(),
+            t: t.clone(),
+            negative,
+            _phantom: PhantomData,
+        });
+
+        range_check_u32_circuit(self, gcd.limbs.clone());
+        range_check_u32_circuit(self, a_div_gcd.limbs.clone());
+        range_check_u32_circuit(self, b_div_gcd.limbs.clone());
+        range_check_u32_circuit(self, s.limbs.clone());
+        range_check_u32_circuit(self, t.limbs.clone());
+
+        // `gcd` divides both `a` and `b`.
+        let a_expected = self.mul_biguint(&gcd, &a_div_gcd);
+        self.connect_biguint(a, &a_expected);
+        let b_expected = self.mul_biguint(&gcd, &b_div_gcd);
+        self.connect_biguint(b, &b_expected);
+
+        // Bezout's identity `a * s - b * t = +-gcd` shows that every common divisor of `a` and
+        // `b` divides `gcd`, so it is the greatest one.
+        let a_times_s = self.mul_biguint(a, &s);
+        let b_times_t = self.mul_biguint(b, &t);
+        let positive = self.not(negative);
+        let lhs_a = self.mul_biguint_by_bool(&a_times_s, positive);
+        let lhs_b = self.mul_biguint_by_bool(&b_times_t, negative);
+        let lhs = self.add_biguint(&lhs_a, &lhs_b);
+        let rhs_a = self.mul_biguint_by_bool(&a_times_s, negative);
+        let rhs_b = self.mul_biguint_by_bool(&b_times_t, positive);
+        let rhs = self.add_biguint(&rhs_a, &rhs_b);
+        let rhs = self.add_biguint(&rhs, &gcd);
+        self.connect_biguint(&lhs, &rhs);
+
+        gcd
+    }
+
+    fn mod_inv_biguint(
+        &mut self,
+        a: &BigUintTarget,
+        modulus: &BigUintTarget,
+    ) -> (BigUintTarget, BoolTarget) {
+        let inv = self.add_virtual_biguint_target(modulus.num_limbs());
+
+        self.add_simple_generator(BigUintModInverseGenerator::<F, D> {
+            a: a.clone(),
+            modulus: modulus.clone(),
+            inv: inv.clone(),
+            _phantom: PhantomData,
+        });
+        range_check_u32_circuit(self, inv.limbs.clone());
+
+        let gcd = self.gcd_biguint(a, modulus);
+        let one = self.constant_biguint(&BigUint::one());
+        let exists = self.is_equal_biguint(&gcd, &one);
+        let not_exists = self.not(exists);
+
+        // If the inverse exists, `a * inv = 1 (mod modulus)` and `inv < modulus`.
+        let product = self.mul_biguint(a, &inv);
+        let product = self.rem_biguint(&product, modulus);
+        let is_inverse = self.is_equal_biguint(&product, &one);
+        let inv_plus_one = self.add_biguint(&inv, &one);
+        let is_reduced = self.cmp_biguint(&inv_plus_one, modulus);
+        let is_valid = self.and(is_inverse, is_reduced);
+        let is_valid_or_missing = self.or(is_valid, not_exists);
+        self.assert_one(is_valid_or_missing.target);
+
+        // Otherwise the inverse is pinned to zero.
+        let zero = self.zero_biguint();
+        let inv_if_missing = self.mul_biguint_by_bool(&inv, not_exists);
+        self.connect_biguint(&inv_if_missing, &zero);
+
+        (inv, exists)
     }
 }
 
@@ -445,6 +699,7 @@ impl<F: RichField + Extendable<D>, const D: usize> SimpleGenerator<F, D>
     fn run_once(&self, witness: &PartitionWitness<F>, out_buffer: &mut GeneratedValues<F>) {
         let a = witness.get_biguint_target(self.a.clone());
         let b = witness.get_biguint_target(self.b.clone());
+        assert!(!b.is_zero(), "BigUintDivRemGenerator: division by zero");
         let (div, rem) = a.div_rem(&b);
 
         out_buffer.set_biguint_target(&self.div, &div);
@@ -504,6 +759,165 @@ impl<F: RichField + Extendable<D>, const D: usize> SimpleGenerator<F, D>
     }
 }
 
+#[derive(Debug)]
+pub struct BigUintGcdGenerator<F: RichField + Extendable<D>, const D: usize> {
+    a: BigUintTarget,
+    b: BigUintTarget,
+    gcd: BigUintTarget,
+    a_div_gcd: BigUintTarget,
+    b_div_gcd: BigUintTarget,
+    s: BigUintTarget,
+    t: BigUintTarget,
+    negative: BoolTarget,
+    _phantom: PhantomData<F>,
+}
+
+impl<F: RichField + Extendable<D>, const D: usize> BigUintGcdGenerator<F, D> {
+    pub fn id() -> String {
+        "BigUintGcdGenerator".to_string()
+    }
+}
+
+impl<F: RichField + Extendable<D>, const D: usize> SimpleGenerator<F, D>
+    for BigUintGcdGenerator<F, D>
+{
+    fn id(&self) -> String {
+        Self::id()
+    }
+
+    fn dependencies(&self) -> Vec<Target> {
+        self.a
+            .limbs
+            .iter()
+            .chain(&self.b.limbs)
+            .map(|&l| l.0)
+            .collect()
+    }
+
+    fn run_once(&self, witness: &PartitionWitness<F>, out_buffer: &mut GeneratedValues<F>) {
+        let a = witness.get_biguint_target(self.a.clone());
+        let b = witness.get_biguint_target(self.b.clone());
+        let ExtendedGcd { gcd, x, y, .. } =
+            BigInt::from(a.clone()).extended_gcd(&BigInt::from(b.clone()));
+        let gcd = gcd.to_biguint().unwrap();
+
+        // The Bezout coefficients have opposite signs (or one is zero), so write the identity as
+        // `a * s - b * t = gcd` or `b * t - a * s = gcd` with `s, t >= 0`.
+        let negative = x.sign() == Sign::Minus || y.sign() == Sign::Plus;
+        let s = x.magnitude().clone();
+        let t = y.magnitude().clone();
+
+        let (a_div_gcd, b_div_gcd) = if gcd.is_zero() {
+            (BigUint::zero(), BigUint::zero())
+        } else {
+            (&a / &gcd, &b / &gcd)
+        };
+
+        out_buffer.set_biguint_target(&self.gcd, &gcd);
+        out_buffer.set_biguint_target(&self.a_div_gcd, &a_div_gcd);
+        out_buffer.set_biguint_target(&self.b_div_gcd, &b_div_gcd);
+        out_buffer.set_biguint_target(&self.s, &s);
+        out_buffer.set_biguint_target(&self.t, &t);
+        out_buffer.set_bool_target(self.negative, negative);
+    }
+
+    fn serialize(&self, dst: &mut Vec<u8>, _: &CommonCircuitData<F, D>) -> IoResult<()> {
+        dst.write_target_biguint(self.a.clone())?;
+        dst.write_target_biguint(self.b.clone())?;
+        dst.write_target_biguint(self.gcd.clone())?;
+        dst.write_target_biguint(self.a_div_gcd.clone())?;
+        dst.write_target_biguint(self.b_div_gcd.clone())?;
+        dst.write_target_biguint(self.s.clone())?;
+        dst.write_target_biguint(self.t.clone())?;
+        dst.write_target_bool(self.negative)
+    }
+
+    fn deserialize(src: &mut Buffer, _: &CommonCircuitData<F, D>) -> IoResult<Self> {
+        let a = src.read_target_biguint()?;
+        let b = src.read_target_biguint()?;
+        let gcd = src.read_target_biguint()?;
+        let a_div_gcd = src.read_target_biguint()?;
+        let b_div_gcd = src.read_target_biguint()?;
+        let s = src.read_target_biguint()?;
+        let t = src.read_target_biguint()?;
+        let negative = src.read_target_bool()?;
+        Ok(Self {
+            a,
+            b,
+            gcd,
+            a_div_gcd,
+            b_div_gcd,
+            s,
+            t,
+            negative,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct BigUintModInverseGenerator<F: RichField + Extendable<D>, const D: usize> {
+    a: BigUintTarget,
+    modulus: BigUintTarget,
+    inv: BigUintTarget,
+    _phantom: PhantomData<F>,
+}
+
+impl<F: RichField + Extendable<D>, const D: usize> BigUintModInverseGenerator<F, D> {
+    pub fn id() -> String {
+        "BigUintModInverseGenerator".to_string()
+    }
+}
+
+impl<F: RichField + Extendable<D>, const D: usize> SimpleGenerator<F, D>
+    for BigUintModInverseGenerator<F, D>
+{
+    fn id(&self) -> String {
+        Self::id()
+    }
+
+    fn dependencies(&self) -> Vec<Target> {
+        self.a
+            .limbs
+            .iter()
+            .chain(&self.modulus.limbs)
+            .map(|&l| l.0)
+            .collect()
+    }
+
+    fn run_once(&self, witness: &PartitionWitness<F>, out_buffer: &mut GeneratedValues<F>) {
+        let a = BigInt::from(witness.get_biguint_target(self.a.clone()));
+        let modulus = BigInt::from(witness.get_biguint_target(self.modulus.clone()));
+
+        let ExtendedGcd { gcd, x, .. } = a.extended_gcd(&modulus);
+        let inv = if gcd.is_one() {
+            x.mod_floor(&modulus).to_biguint().unwrap()
+        } else {
+            BigUint::zero()
+        };
+
+        out_buffer.set_biguint_target(&self.inv, &inv);
+    }
+
+    fn serialize(&self, dst: &mut Vec<u8>, _: &CommonCircuitData<F, D>) -> IoResult<()> {
+        dst.write_target_biguint(self.a.clone())?;
+        dst.write_target_biguint(self.modulus.clone())?;
+        dst.write_target_biguint(self.inv.clone())
+    }
+
+    fn deserialize(src: &mut Buffer, _: &CommonCircuitData<F, D>) -> IoResult<Self> {
+        let a = src.read_target_biguint()?;
+        let modulus = src.read_target_biguint()?;
+        let inv = src.read_target_biguint()?;
+        Ok(Self {
+            a,
+            modulus,
+            inv,
+            _phantom: PhantomData,
+        })
+    }
+}
+
 pub trait WriteBigUint {
     fn write_target_biguint(&mut self, x: BigUintTarget) -> IoResult<()>;
 }
@@ -695,4 +1109,229 @@ mod tests {
         let proof = data.prove(pw).unwrap();
         data.verify(proof).unwrap()
     }
+
+    #[test]
+    fn test_biguint_div_rem_limb_counts() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        let mut rng = OsRng;
+
+        let config = CircuitConfig::standard_recursion_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+
+        for (a_limbs, b_limbs) in [(4, 1), (4, 2), (3, 3), (2, 3)] {
+            let x_value =
+                BigUint::from_slice(&(0..a_limbs).map(|_| rng.gen::<u32>()).collect_vec());
+            let mut y_value =
+                BigUint::from_slice(&(0..b_limbs).map(|_| rng.gen::<u32>()).collect_vec());
+            if y_value.is_zero() {
+                y_value = BigUint::one();
+            }
+            let (expected_div_value, expected_rem_value) = x_value.div_rem(&y_value);
+
+            let x = builder.add_virtual_biguint_target(a_limbs);
+            let y = builder.add_virtual_biguint_target(b_limbs);
+            pw.set_biguint_target(&x, &x_value);
+            pw.set_biguint_target(&y, &y_value);
+            let (div, rem) = builder.div_rem_biguint(&x, &y);
+
+            let expected_div = builder.constant_biguint(&expected_div_value);
+            let expected_rem = builder.constant_biguint(&expected_rem_value);
+            builder.connect_biguint(&div, &expected_div);
+            builder.connect_biguint(&rem, &expected_rem);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap()
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero")]
+    fn test_biguint_div_rem_by_zero() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_recursion_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+
+        let x = builder.constant_biguint(&BigUint::from_u32(7).unwrap());
+        let y = builder.add_virtual_biguint_target(1);
+        pw.set_biguint_target(&y, &BigUint::zero());
+        builder.div_rem_biguint(&x, &y);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap()
+    }
+
+    #[test]
+    fn test_biguint_shifts() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        let mut rng = OsRng;
+
+        let x_value = BigUint::from_slice(&(0..4).map(|_| rng.gen::<u32>()).collect_vec());
+
+        let config = CircuitConfig::standard_recursion_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+
+        let x = builder.add_virtual_biguint_target(4);
+        pw.set_biguint_target(&x, &x_value);
+
+        for n in [0, 1, 13, 31, 32, 33, 64, 100, 127, 128, 200] {
+            let shl = builder.shl_biguint(&x, n);
+            let expected_shl = builder.constant_biguint(&(&x_value << n));
+            builder.connect_biguint(&shl, &expected_shl);
+
+            let shr = builder.shr_biguint(&x, n);
+            let expected_shr = builder.constant_biguint(&(&x_value >> n));
+            builder.connect_biguint(&shr, &expected_shr);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap()
+    }
+
+    #[test]
+    fn test_biguint_modpow() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        // A toy RSA key with N = 61 * 53.
+        let n_value = BigUint::from_u32(3233).unwrap();
+        let e_value = BigUint::from_u32(17).unwrap();
+        let d_value = BigUint::from_u32(2753).unwrap();
+        let message_value = BigUint::from_u32(65).unwrap();
+        let signature_value = message_value.modpow(&d_value, &n_value);
+
+        let config = CircuitConfig::standard_recursion_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+
+        let n = builder.constant_biguint(&n_value);
+        let e = builder.constant_biguint(&e_value);
+        let signature = builder.add_virtual_biguint_target(1);
+        pw.set_biguint_target(&signature, &signature_value);
+
+        let recovered = builder.modpow_biguint(&signature, &e, &n);
+        let expected_message = builder.constant_biguint(&message_value);
+        builder.connect_biguint(&recovered, &expected_message);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap()
+    }
+
+    #[test]
+    fn test_biguint_gcd_and_mod_inv() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_recursion_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+
+        // Coprime and non-coprime pairs, including zero operands.
+        let cases = [
+            (17u64, 3233u64),
+            (3233, 17),
+            (61 * 1_000_003, 61 * 53),
+            (1 << 40, 3 << 20),
+            (0, 3233),
+            (3233, 0),
+            (0, 0),
+        ];
+        for (a_value, b_value) in cases {
+            let a_value = BigUint::from_u64(a_value).unwrap();
+            let b_value = BigUint::from_u64(b_value).unwrap();
+            let a = builder.add_virtual_biguint_target(2);
+            let b = builder.add_virtual_biguint_target(2);
+            pw.set_biguint_target(&a, &a_value);
+            pw.set_biguint_target(&b, &b_value);
+
+            let gcd = builder.gcd_biguint(&a, &b);
+            let gcd_expected = builder.constant_biguint(&a_value.gcd(&b_value));
+            builder.connect_biguint(&gcd, &gcd_expected);
+
+            if b_value.is_zero() {
+                continue;
+            }
+            let (inv, exists) = builder.mod_inv_biguint(&a, &b);
+            let exists_expected = builder.constant_bool(a_value.gcd(&b_value).is_one());
+            builder.connect(exists.target, exists_expected.target);
+            let inv_value = if a_value.gcd(&b_value).is_one() {
+                BigInt::from(a_value)
+                    .extended_gcd(&BigInt::from(b_value.clone()))
+                    .x
+                    .mod_floor(&BigInt::from(b_value))
+                    .to_biguint()
+                    .unwrap()
+            } else {
+                BigUint::zero()
+            };
+            let inv_expected = builder.constant_biguint(&inv_value);
+            builder.connect_biguint(&inv, &inv_expected);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap()
+    }
+
+    #[test]
+    fn test_biguint_is_equal() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        let mut rng = OsRng;
+
+        let x_value = BigUint::from_u64(rng.gen()).unwrap();
+        let y_value = &x_value + 1u32;
+
+        let config = CircuitConfig::standard_recursion_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+
+        // The same value with two, three and five limbs.
+        let x = builder.add_virtual_biguint_target(2);
+        let x_wide = builder.add_virtual_biguint_target(3);
+        let x_wider = builder.add_virtual_biguint_target(5);
+        let y_wide = builder.add_virtual_biguint_target(3);
+        pw.set_biguint_target(&x, &x_value);
+        pw.set_biguint_target(&x_wide, &x_value);
+        pw.set_biguint_target(&x_wider, &x_value);
+        pw.set_biguint_target(&y_wide, &y_value);
+        // Differs from `x` only in a high limb that `x` does not have.
+        let z = builder.add_virtual_biguint_target(4);
+        pw.set_biguint_target(&z, &(&x_value + (BigUint::one() << 96)));
+
+        let t = builder._true();
+        let f = builder._false();
+        for (a, b, expected) in [
+            (&x, &x_wide, t),
+            (&x_wider, &x, t),
+            (&x_wide, &x_wider, t),
+            (&x, &y_wide, f),
+            (&y_wide, &x_wider, f),
+            (&x, &z, f),
+            (&z, &x, f),
+        ] {
+            let is_equal = builder.is_equal_biguint(a, b);
+            builder.connect(is_equal.target, expected.target);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap()
+    }
 }
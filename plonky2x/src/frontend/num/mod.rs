@@ -1,3 +1,4 @@
 pub mod biguint;
+pub mod modular;
 pub mod nonnative;
 pub mod u32;
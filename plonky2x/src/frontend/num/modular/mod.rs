@@ -0,0 +1,138 @@
+use plonky2::field::extension::Extendable;
+use plonky2::hash::hash_types::RichField;
+use plonky2::iop::target::BoolTarget;
+use plonky2::plonk::circuit_builder::CircuitBuilder;
+
+use crate::frontend::num::biguint::{BigUintTarget, CircuitBuilderBiguint};
+
+/// An integer modulo a modulus chosen at proving time, which need not be prime.
+///
+/// Unlike `NonNativeTarget`, the modulus is carried alongside the value as a `BigUintTarget`, so
+/// it can be used for arithmetic over composite moduli such as an RSA modulus. The value is kept
+/// reduced, i.e. less than the modulus.
+#[derive(Clone, Debug, Default)]
+pub struct ModTarget {
+    pub value: BigUintTarget,
+    pub modulus: BigUintTarget,
+}
+
+pub trait CircuitBuilderModular<F: RichField + Extendable<D>, const D: usize> {
+    /// Returns `x % modulus` as a `ModTarget`.
+    fn reduce_mod(&mut self, x: &BigUintTarget, modulus: &BigUintTarget) -> ModTarget;
+
+    /// Asserts that two `ModTarget`s share the same modulus.
+    fn connect_moduli(&mut self, a: &ModTarget, b: &ModTarget);
+
+    fn add_mod(&mut self, a: &ModTarget, b: &ModTarget) -> ModTarget;
+
+    fn mul_mod(&mut self, a: &ModTarget, b: &ModTarget) -> ModTarget;
+
+    /// Returns the inverse of `a` together with a flag indicating whether it exists, i.e. whether
+    /// `a` is coprime to the modulus. If it does not, the returned inverse is zero.
+    fn inv_mod(&mut self, a: &ModTarget) -> (ModTarget, BoolTarget);
+}
+
+impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderModular<F, D>
+    for CircuitBuilder<F, D>
+{
+    fn reduce_mod(&mut self, x: &BigUintTarget, modulus: &BigUintTarget) -> ModTarget {
+        let value = self.rem_biguint(x, modulus);
+
+        ModTarget {
+            value,
+            modulus: modulus.clone(),
+        }
+    }
+
+    fn connect_moduli(&mut self, a: &ModTarget, b: &ModTarget) {
+        self.connect_biguint(&a.modulus, &b.modulus);
+    }
+
+    fn add_mod(&mut self, a: &ModTarget, b: &ModTarget) -> ModTarget {
+        self.connect_moduli(a, b);
+        let sum = self.add_biguint(&a.value, &b.value);
+        self.reduce_mod(&sum, &a.modulus)
+    }
+
+    fn mul_mod(&mut self, a: &ModTarget, b: &ModTarget) -> ModTarget {
+        self.connect_moduli(a, b);
+        let prod = self.mul_biguint(&a.value, &b.value);
+        self.reduce_mod(&prod, &a.modulus)
+    }
+
+    fn inv_mod(&mut self, a: &ModTarget) -> (ModTarget, BoolTarget) {
+        let (inv, exists) = self.mod_inv_biguint(&a.value, &a.modulus);
+        let inv = ModTarget {
+            value: inv,
+            modulus: a.modulus.clone(),
+        };
+
+        (inv, exists)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::bigint::RandBigInt;
+    use num::{BigUint, FromPrimitive, One};
+    use plonky2::iop::witness::PartialWitness;
+    use plonky2::plonk::circuit_data::CircuitConfig;
+    use plonky2::plonk::config::{GenericConfig, PoseidonGoldilocksConfig};
+    use rand::thread_rng;
+
+    use super::*;
+
+    #[test]
+    fn test_mul_mod_composite() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        let mut rng = thread_rng();
+
+        // N = (2^61 - 1) * (2^31 - 1) is a product of two Mersenne primes.
+        let n_value = BigUint::from_u64((1 << 61) - 1).unwrap() * ((1u64 << 31) - 1);
+        let a_value = rng.gen_biguint_below(&n_value);
+        let b_value = rng.gen_biguint_below(&n_value);
+        let c_value = BigUint::from_u32(65537).unwrap();
+
+        let config = CircuitConfig::standard_recursion_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+
+        let n = builder.add_virtual_biguint_target(3);
+        pw.set_biguint_target(&n, &n_value);
+
+        let a_biguint = builder.constant_biguint(&a_value);
+        let b_biguint = builder.constant_biguint(&b_value);
+        let c_biguint = builder.constant_biguint(&c_value);
+        let a = builder.reduce_mod(&a_biguint, &n);
+        let b = builder.reduce_mod(&b_biguint, &n);
+        let c = builder.reduce_mod(&c_biguint, &n);
+
+        let product = builder.mul_mod(&a, &b);
+        let expected_product = builder.constant_biguint(&((&a_value * &b_value) % &n_value));
+        builder.connect_biguint(&product.value, &expected_product);
+
+        let sum = builder.add_mod(&a, &b);
+        let expected_sum = builder.constant_biguint(&((&a_value + &b_value) % &n_value));
+        builder.connect_biguint(&sum.value, &expected_sum);
+
+        let (c_inv, c_inv_exists) = builder.inv_mod(&c);
+        builder.assert_one(c_inv_exists.target);
+        let c_times_c_inv = builder.mul_mod(&c, &c_inv);
+        let one = builder.constant_biguint(&BigUint::one());
+        builder.connect_biguint(&c_times_c_inv.value, &one);
+
+        // A factor of N has no inverse; witness generation must still succeed.
+        let p_biguint = builder.constant_biguint(&BigUint::from_u64((1 << 61) - 1).unwrap());
+        let p = builder.reduce_mod(&p_biguint, &n);
+        let (p_inv, p_inv_exists) = builder.inv_mod(&p);
+        builder.assert_zero(p_inv_exists.target);
+        let zero = builder.zero_biguint();
+        builder.connect_biguint(&p_inv.value, &zero);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap()
+    }
+}
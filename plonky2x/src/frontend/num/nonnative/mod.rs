@@ -1,3 +1,4 @@
+pub mod montgomery;
 #[allow(clippy::module_inception)]
 pub mod nonnative;
 pub mod split_nonnative;
@@ -0,0 +1,203 @@
+use core::marker::PhantomData;
+
+use num::{BigInt, BigUint, Integer, One};
+use plonky2::field::extension::Extendable;
+use plonky2::field::types::PrimeField;
+use plonky2::hash::hash_types::RichField;
+use plonky2::plonk::circuit_builder::CircuitBuilder;
+use plonky2::util::ceil_div_usize;
+
+use crate::frontend::num::biguint::{BigUintTarget, CircuitBuilderBiguint};
+use crate::frontend::num::nonnative::nonnative::{CircuitBuilderNonNative, NonNativeTarget};
+use crate::frontend::num::u32::gadgets::arithmetic_u32::CircuitBuilderU32;
+
+/// An element `x` of `FF` stored in Montgomery form, i.e. as `x * R mod |FF|` where
+/// `R = 2^(32 * num_limbs)`.
+#[derive(Clone, Debug, Default)]
+pub struct MontgomeryNonNativeTarget<FF: PrimeField> {
+    pub value: BigUintTarget,
+    pub _phantom: PhantomData<FF>,
+}
+
+pub trait CircuitBuilderMontgomery<F: RichField + Extendable<D>, const D: usize> {
+    /// Converts a reduced `x` into Montgomery form.
+    fn to_montgomery<FF: PrimeField>(
+        &mut self,
+        x: &NonNativeTarget<FF>,
+    ) -> MontgomeryNonNativeTarget<FF>;
+
+    /// Converts `x` out of Montgomery form.
+    fn from_montgomery<FF: PrimeField>(
+        &mut self,
+        x: &MontgomeryNonNativeTarget<FF>,
+    ) -> NonNativeTarget<FF>;
+
+    /// Multiplies two elements in Montgomery form, using REDC to divide out the extra factor of
+    /// `R` without a division hint.
+    fn mont_mul<FF: PrimeField>(
+        &mut self,
+        a: &MontgomeryNonNativeTarget<FF>,
+        b: &MontgomeryNonNativeTarget<FF>,
+    ) -> MontgomeryNonNativeTarget<FF>;
+}
+
+impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderMontgomery<F, D>
+    for CircuitBuilder<F, D>
+{
+    fn to_montgomery<FF: PrimeField>(
+        &mut self,
+        x: &NonNativeTarget<FF>,
+    ) -> MontgomeryNonNativeTarget<FF> {
+        let r = FF::from_noncanonical_biguint(montgomery_r::<FF>());
+        let x_times_r = self.mul_const_nonnative(x, r);
+        // `redc` corrects with a single conditional subtraction, which needs reduced inputs.
+        self.assert_nonnative_reduced(&x_times_r);
+
+        MontgomeryNonNativeTarget {
+            value: x_times_r.value,
+            _phantom: PhantomData,
+        }
+    }
+
+    fn from_montgomery<FF: PrimeField>(
+        &mut self,
+        x: &MontgomeryNonNativeTarget<FF>,
+    ) -> NonNativeTarget<FF> {
+        NonNativeTarget {
+            value: redc::<F, D, FF>(self, &x.value),
+            _phantom: PhantomData,
+        }
+    }
+
+    fn mont_mul<FF: PrimeField>(
+        &mut self,
+        a: &MontgomeryNonNativeTarget<FF>,
+        b: &MontgomeryNonNativeTarget<FF>,
+    ) -> MontgomeryNonNativeTarget<FF> {
+        let product = self.mul_biguint(&a.value, &b.value);
+
+        MontgomeryNonNativeTarget {
+            value: redc::<F, D, FF>(self, &product),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// Returns `R = 2^(32 * num_limbs)`.
+fn montgomery_r<FF: PrimeField>() -> BigUint {
+    BigUint::one() << (32 * ceil_div_usize(FF::BITS, 32))
+}
+
+/// Returns `t * R^-1 mod |FF|` for `t < |FF| * R`.
+fn redc<F: RichField + Extendable<D>, const D: usize, FF: PrimeField>(
+    builder: &mut CircuitBuilder<F, D>,
+    t: &BigUintTarget,
+) -> BigUintTarget {
+    let num_limbs = ceil_div_usize(FF::BITS, 32);
+    let modulus = FF::order();
+    let r = montgomery_r::<FF>();
+
+    // `N' = -|FF|^-1 mod R`, which exists since `|FF|` is odd.
+    let modulus_inv = BigInt::from(modulus.clone())
+        .extended_gcd(&BigInt::from(r.clone()))
+        .x
+        .mod_floor(&BigInt::from(r.clone()));
+    let n_prime = &r - modulus_inv.to_biguint().unwrap();
+
+    let mut t_limbs = t.limbs.clone();
+    if t_limbs.len() < num_limbs {
+        t_limbs.resize(num_limbs, builder.zero_u32());
+    }
+    let t = BigUintTarget { limbs: t_limbs };
+
+    // `m = (t mod R) * N' mod R`, so that `t + m * |FF|` is divisible by `R`.
+    let t_low = BigUintTarget {
+        limbs: t.limbs[..num_limbs].to_vec(),
+    };
+    let m = builder.mul_biguint_by_constant(&t_low, &n_prime);
+    let m = BigUintTarget {
+        limbs: m.limbs[..num_limbs].to_vec(),
+    };
+    let m_times_modulus = builder.mul_biguint_by_constant(&m, &modulus);
+    let u = builder.add_biguint(&t, &m_times_modulus);
+    for &limb in u.limbs[..num_limbs].iter() {
+        builder.assert_zero_u32(limb);
+    }
+
+    // `u / R < 2 * |FF|`, so one conditional subtraction finishes the job.
+    let estimate = BigUintTarget {
+        limbs: u.limbs[num_limbs..].to_vec(),
+    };
+    let modulus_minus_one = builder.constant_biguint(&(&modulus - BigUint::one()));
+    let is_reduced = builder.cmp_biguint(&estimate, &modulus_minus_one);
+    let is_off_by_one = builder.not(is_reduced);
+    let modulus_target = builder.nonnative_modulus::<FF>();
+    let correction = builder.mul_biguint_by_bool(&modulus_target, is_off_by_one);
+    let reduced = builder.sub_biguint(&estimate, &correction);
+
+    for &limb in reduced.limbs[num_limbs..].iter() {
+        builder.assert_zero_u32(limb);
+    }
+
+    BigUintTarget {
+        limbs: reduced.limbs[..num_limbs].to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use plonky2::field::secp256k1_base::Secp256K1Base;
+    use plonky2::field::types::{Field, Sample};
+    use plonky2::iop::witness::PartialWitness;
+    use plonky2::plonk::circuit_data::CircuitConfig;
+    use plonky2::plonk::config::{GenericConfig, PoseidonGoldilocksConfig};
+
+    use super::*;
+
+    #[test]
+    fn test_montgomery_mul_chain() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let pw = PartialWitness::new();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+
+        let values = (0..16).map(|_| FF::rand()).collect::<Vec<_>>();
+        let targets = values
+            .iter()
+            .map(|&x| builder.constant_nonnative(x))
+            .collect::<Vec<_>>();
+
+        let mut product = targets[0].clone();
+        let mut product_montgomery = builder.to_montgomery(&targets[0]);
+        for x in targets[1..].iter() {
+            product = builder.mul_nonnative(&product, x);
+            let x_montgomery = builder.to_montgomery(x);
+            product_montgomery = builder.mont_mul(&product_montgomery, &x_montgomery);
+        }
+        let product_from_montgomery = builder.from_montgomery(&product_montgomery);
+        builder.connect_nonnative(&product_from_montgomery, &product);
+
+        let product_expected = builder.constant_nonnative(values.iter().copied().product::<FF>());
+        builder.connect_nonnative(&product_from_montgomery, &product_expected);
+
+        // Converting in and straight back out again is the identity.
+        for (x, &x_value) in targets.iter().zip(values.iter()) {
+            let x_montgomery = builder.to_montgomery(x);
+            let x_round_trip = builder.from_montgomery(&x_montgomery);
+            let x_expected = builder.constant_nonnative(x_value);
+            builder.connect_nonnative(&x_round_trip, &x_expected);
+        }
+        let one = builder.constant_nonnative(FF::ONE);
+        let one_montgomery = builder.to_montgomery(&one);
+        let one_round_trip = builder.from_montgomery(&one_montgomery);
+        builder.connect_nonnative(&one_round_trip, &one);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+}
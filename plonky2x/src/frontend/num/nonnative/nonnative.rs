@@ -1,19 +1,21 @@
+use alloc::collections::BTreeMap;
 use alloc::string::{String, ToString};
-use alloc::vec;
 use alloc::vec::Vec;
+use alloc::{format, vec};
 use core::marker::PhantomData;
 
-use num::{BigUint, Integer, One, Zero};
+use array_macro::array;
+use num::{BigUint, Integer, One, ToPrimitive, Zero};
 use plonky2::field::extension::Extendable;
-use plonky2::field::types::PrimeField;
+use plonky2::field::types::{Field, PrimeField, PrimeField64};
 use plonky2::hash::hash_types::RichField;
 use plonky2::iop::generator::{GeneratedValues, SimpleGenerator};
 use plonky2::iop::target::{BoolTarget, Target};
-use plonky2::iop::witness::{PartitionWitness, WitnessWrite};
+use plonky2::iop::witness::{PartitionWitness, Witness, WitnessWrite};
 use plonky2::plonk::circuit_builder::CircuitBuilder as BaseCircuitBuilder;
-use plonky2::plonk::circuit_data::CommonCircuitData;
-use plonky2::util::ceil_div_usize;
-use plonky2::util::serialization::{Buffer, IoResult, Read, Write};
+use plonky2::plonk::circuit_data::{CircuitConfig, CommonCircuitData};
+use plonky2::util::serialization::{Buffer, IoError, IoResult, Read, Write};
+use plonky2::util::{ceil_div_usize, log2_ceil};
 
 use crate::frontend::num::biguint::{
     BigUintTarget, CircuitBuilderBiguint, GeneratedValuesBigUint, ReadBigUint, WitnessBigUint,
@@ -23,7 +25,11 @@ use crate::frontend::num::u32::gadgets::arithmetic_u32::{CircuitBuilderU32, U32T
 use crate::frontend::num::u32::gadgets::range_check::range_check_u32_circuit;
 use crate::frontend::num::u32::serialization::{ReadU32, WriteU32};
 use crate::frontend::num::u32::witness::GeneratedValuesU32;
-use crate::prelude::{CircuitBuilder, CircuitVariable, PlonkParameters, Variable};
+use crate::frontend::vars::EvmVariable;
+use crate::prelude::{
+    Add, Bytes32Variable, CircuitBuilder, CircuitVariable, Mul, Neg, PlonkParameters, Sub,
+    U256Variable, U32Variable, Variable,
+};
 
 #[derive(Clone, Debug, Default)]
 pub struct NonNativeTarget<FF: PrimeField> {
@@ -35,6 +41,13 @@ fn num_nonnative_limbs<FF: PrimeField>() -> usize {
     ceil_div_usize(FF::BITS, 32)
 }
 
+/// Returns the id of the nonnative generator `name` over `FF`. The modulus is included so that
+/// generators of the same type over different fields get distinct ids in the serialization
+/// registry.
+fn nonnative_generator_id<FF: PrimeField>(name: &str) -> String {
+    format!("{}<0x{}>", name, FF::order().to_str_radix(16))
+}
+
 impl<FF: PrimeField> CircuitVariable for NonNativeTarget<FF> {
     type ValueType<F: RichField> = FF;
 
@@ -59,13 +72,34 @@ impl<FF: PrimeField> CircuitVariable for NonNativeTarget<FF> {
             .collect::<Vec<Variable>>()
     }
 
+    /// Like the default, but also constrains any padding variables past the limbs to be zero.
+    fn from_variables<L: PlonkParameters<D>, const D: usize>(
+        builder: &mut CircuitBuilder<L, D>,
+        variables: &[Variable],
+    ) -> Self {
+        let variable = Self::from_variables_unsafe(variables);
+        for padding in &variables[num_nonnative_limbs::<FF>()..] {
+            builder.api.assert_zero(padding.0);
+        }
+        variable.assert_is_valid(builder);
+        variable
+    }
+
+    /// Takes the first `num_nonnative_limbs::<FF>()` variables as the limbs. Any further
+    /// variables are treated as zero padding and ignored without adding constraints; use
+    /// `from_variables` to constrain the padding to be zero.
     fn from_variables_unsafe(variables: &[Variable]) -> Self {
         let num_limbs = num_nonnative_limbs::<FF>();
-        let u32s = variables
+        assert!(
+            variables.len() >= num_limbs,
+            "expected at least {} variables, got {}",
+            num_limbs,
+            variables.len()
+        );
+        let u32s = variables[..num_limbs]
             .iter()
             .map(|x| U32Target(x.0))
             .collect::<Vec<U32Target>>();
-        assert_eq!(u32s.len(), num_limbs);
         Self {
             value: BigUintTarget { limbs: u32s },
             _phantom: PhantomData,
@@ -76,10 +110,7 @@ impl<FF: PrimeField> CircuitVariable for NonNativeTarget<FF> {
         &self,
         builder: &mut CircuitBuilder<L, D>,
     ) {
-        let modulus = builder.api.constant_biguint(&FF::order());
-        let cmp = builder.api.cmp_biguint(&self.value, &modulus);
-        let one = builder.api.one();
-        builder.api.connect(cmp.target, one);
+        builder.api.assert_nonnative_reduced(self);
     }
 
     fn nb_elements() -> usize {
@@ -88,25 +119,132 @@ impl<FF: PrimeField> CircuitVariable for NonNativeTarget<FF> {
 
     fn elements<F: RichField>(value: Self::ValueType<F>) -> Vec<F> {
         let biguint = value.to_canonical_biguint();
-        let limbs = biguint.to_u32_digits();
+        let mut limbs = biguint.to_u32_digits();
+        // `to_u32_digits` drops leading zero limbs, so pad back to the full width.
         let num_limbs = num_nonnative_limbs::<FF>();
-        assert_eq!(limbs.len(), num_limbs);
+        limbs.resize(num_limbs, 0);
         limbs
             .iter()
             .flat_map(|x| Variable::elements(F::from_canonical_u32(*x)))
             .collect::<Vec<_>>()
     }
 
+    /// Reads the limbs from the first `num_nonnative_limbs::<FF>()` elements. Panics if a limb does
+    /// not fit in 32 bits or if any further padding element is nonzero.
     fn from_elements<F: RichField>(elements: &[F]) -> Self::ValueType<F> {
-        let u32_slice = elements
+        let num_limbs = num_nonnative_limbs::<FF>();
+        assert!(
+            elements.len() >= num_limbs,
+            "expected at least {} elements, got {}",
+            num_limbs,
+            elements.len()
+        );
+        assert!(
+            elements[num_limbs..].iter().all(|x| x.is_zero()),
+            "nonzero padding element past the {} nonnative limbs",
+            num_limbs
+        );
+        let u32_slice = elements[..num_limbs]
             .iter()
-            .map(|x| Variable::from_elements(&[*x]).to_canonical_u64() as u32)
+            .map(|x| {
+                let limb = Variable::from_elements(&[*x]).to_canonical_u64();
+                u32::try_from(limb).expect("nonnative limb does not fit in 32 bits")
+            })
             .collect::<Vec<u32>>();
         let big_uint = BigUint::from_slice(&u32_slice);
         FF::from_noncanonical_biguint(big_uint)
     }
 }
 
+impl<L: PlonkParameters<D>, const D: usize, FF: PrimeField> Add<L, D> for NonNativeTarget<FF> {
+    type Output = Self;
+
+    fn add(self, rhs: Self, builder: &mut CircuitBuilder<L, D>) -> Self::Output {
+        builder.api.add_nonnative(&self, &rhs)
+    }
+}
+
+impl<L: PlonkParameters<D>, const D: usize, FF: PrimeField> Sub<L, D> for NonNativeTarget<FF> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self, builder: &mut CircuitBuilder<L, D>) -> Self::Output {
+        builder.api.sub_nonnative(&self, &rhs)
+    }
+}
+
+impl<L: PlonkParameters<D>, const D: usize, FF: PrimeField> Mul<L, D> for NonNativeTarget<FF> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self, builder: &mut CircuitBuilder<L, D>) -> Self::Output {
+        builder.api.mul_nonnative(&self, &rhs)
+    }
+}
+
+impl<L: PlonkParameters<D>, const D: usize, FF: PrimeField> Neg<L, D> for NonNativeTarget<FF> {
+    type Output = Self;
+
+    fn neg(self, builder: &mut CircuitBuilder<L, D>) -> Self::Output {
+        builder.api.neg_nonnative(&self)
+    }
+}
+
+/// Fails to compile when elements of `FF` do not fit in 256 bits.
+struct AssertFitsIn256Bits<FF: PrimeField>(PhantomData<FF>);
+
+impl<FF: PrimeField> AssertFitsIn256Bits<FF> {
+    const OK: () = assert!(FF::BITS <= 256, "field elements must fit in 256 bits");
+}
+
+impl<L: PlonkParameters<D>, const D: usize> CircuitBuilder<L, D> {
+    /// Converts a reduced `NonNativeTarget` into a `U256Variable`. Both use little-endian u32
+    /// limbs, so this only pads the limbs and does not add any constraints.
+    pub fn nonnative_to_u256<FF: PrimeField>(&mut self, x: &NonNativeTarget<FF>) -> U256Variable {
+        let _ = AssertFitsIn256Bits::<FF>::OK;
+        assert!(x.value.num_limbs() <= 8);
+
+        let zero = self.api.zero();
+        let limbs =
+            array![i => U32Variable(Variable(x.value.limbs.get(i).map_or(zero, |l| l.0))); 8];
+        U256Variable { limbs }
+    }
+
+    /// Converts a `U256Variable` into a `NonNativeTarget`, reducing it modulo `|FF|`.
+    pub fn u256_to_nonnative<FF: PrimeField>(&mut self, x: U256Variable) -> NonNativeTarget<FF> {
+        let _ = AssertFitsIn256Bits::<FF>::OK;
+
+        let limbs = x.limbs.iter().map(|l| U32Target(l.0 .0)).collect();
+        self.api.reduce(&BigUintTarget { limbs })
+    }
+
+    /// Interprets 32 bytes as an integer in the given byte order and reduces it modulo `|FF|`.
+    pub fn nonnative_from_bytes32<FF: PrimeField>(
+        &mut self,
+        bytes: Bytes32Variable,
+        big_endian: bool,
+    ) -> NonNativeTarget<FF> {
+        let mut bytes = bytes.as_bytes();
+        if !big_endian {
+            bytes.reverse();
+        }
+        let x = U256Variable::decode(self, &bytes);
+        self.u256_to_nonnative(x)
+    }
+
+    /// Encodes a reduced `NonNativeTarget` as 32 bytes in the given byte order.
+    pub fn nonnative_to_bytes32<FF: PrimeField>(
+        &mut self,
+        x: &NonNativeTarget<FF>,
+        big_endian: bool,
+    ) -> Bytes32Variable {
+        let x = self.nonnative_to_u256(x);
+        let mut bytes = x.encode(self);
+        if !big_endian {
+            bytes.reverse();
+        }
+        Bytes32Variable::from(bytes.as_slice())
+    }
+}
+
 pub trait CircuitBuilderNonNative<F: RichField + Extendable<D>, const D: usize> {
     fn num_nonnative_limbs<FF: PrimeField>() -> usize {
         ceil_div_usize(FF::BITS, 32)
@@ -123,6 +261,10 @@ pub trait CircuitBuilderNonNative<F: RichField + Extendable<D>, const D: usize>
 
     fn zero_nonnative<FF: PrimeField>(&mut self) -> NonNativeTarget<FF>;
 
+    /// Returns `|FF|` as a constant. Constants are deduplicated by value, so every call for the
+    /// same modulus returns the same targets rather than registering new ones.
+    fn nonnative_modulus<FF: PrimeField>(&mut self) -> BigUintTarget;
+
     // Assert that two NonNativeTarget's, both assumed to be in reduced form, are equal.
     fn connect_nonnative<FF: PrimeField>(
         &mut self,
@@ -130,25 +272,53 @@ pub trait CircuitBuilderNonNative<F: RichField + Extendable<D>, const D: usize>
         rhs: &NonNativeTarget<FF>,
     );
 
+    /// Asserts that `x < |FF|`. Useful as a guard when tracking down inputs that break the reduced
+    /// form assumption of other methods.
+    fn assert_nonnative_reduced<FF: PrimeField>(&mut self, x: &NonNativeTarget<FF>);
+
+    /// Allocates a `NonNativeTarget` without constraining its limbs. Use
+    /// `add_virtual_nonnative_target_checked` for values coming from an untrusted witness.
     fn add_virtual_nonnative_target<FF: PrimeField>(&mut self) -> NonNativeTarget<FF>;
 
+    /// Allocates a `NonNativeTarget` whose limbs are range-checked to be u32s and whose value is
+    /// asserted to be `< |FF|`.
+    fn add_virtual_nonnative_target_checked<FF: PrimeField>(&mut self) -> NonNativeTarget<FF>;
+
     fn add_virtual_nonnative_target_sized<FF: PrimeField>(
         &mut self,
         num_limbs: usize,
     ) -> NonNativeTarget<FF>;
 
+    /// Registers the limbs of `x`, least significant first, as public inputs. The value can be
+    /// recovered from the proof's public inputs with `read_nonnative_public_input`.
+    fn register_nonnative_public_input<FF: PrimeField>(&mut self, x: &NonNativeTarget<FF>);
+
     fn add_nonnative<FF: PrimeField>(
         &mut self,
         a: &NonNativeTarget<FF>,
         b: &NonNativeTarget<FF>,
     ) -> NonNativeTarget<FF>;
 
+    /// Adds two `NonNativeTarget`s like `add_nonnative`, but without asserting that the sum is
+    /// below `|FF|`. The result satisfies `result = a + b - overflow * |FF|` for a boolean
+    /// `overflow` and fits in `num_nonnative_limbs::<FF>()` limbs. An honest prover subtracts the
+    /// modulus at most once, so the result is `< |FF|` iff the inputs were reduced and at most one
+    /// overflow occurred; chained sums may exceed it. A dishonest prover may also leave it
+    /// unreduced, so it is only guaranteed to be `< |FF|` after `reduce_nonnative`.
+    fn add_nonnative_no_reduce<FF: PrimeField>(
+        &mut self,
+        a: &NonNativeTarget<FF>,
+        b: &NonNativeTarget<FF>,
+    ) -> NonNativeTarget<FF>;
+
     fn mul_nonnative_by_bool<FF: PrimeField>(
         &mut self,
         a: &NonNativeTarget<FF>,
         b: BoolTarget,
     ) -> NonNativeTarget<FF>;
 
+    /// Returns `x` if `b` is true and `y` otherwise. The limbs of the chosen branch are passed
+    /// through unchanged, so neither branch needs to be reduced and no reduction is performed.
     fn if_nonnative<FF: PrimeField>(
         &mut self,
         b: BoolTarget,
@@ -156,11 +326,50 @@ pub trait CircuitBuilderNonNative<F: RichField + Extendable<D>, const D: usize>
         y: &NonNativeTarget<FF>,
     ) -> NonNativeTarget<FF>;
 
+    /// Like `if_nonnative`, but returns the chosen branch without adding any constraints when `b`
+    /// is a constant.
+    fn select_nonnative<FF: PrimeField>(
+        &mut self,
+        b: BoolTarget,
+        x: &NonNativeTarget<FF>,
+        y: &NonNativeTarget<FF>,
+    ) -> NonNativeTarget<FF>;
+
     fn add_many_nonnative<FF: PrimeField>(
         &mut self,
         to_add: &[NonNativeTarget<FF>],
     ) -> NonNativeTarget<FF>;
 
+    /// Returns the reduced sum of `summands`, which are assumed to be reduced. Since each summand
+    /// is below `p`, the quotient `sum / p` is below `summands.len()`, so the overflow is given
+    /// `ceil(log2(summands.len()) / 32)` limbs. Unlike `add_many_nonnative`, whose overflow is a
+    /// single `U32Target`, this places no limit on the number of summands.
+    fn sum_nonnative<FF: PrimeField>(
+        &mut self,
+        summands: &[NonNativeTarget<FF>],
+    ) -> NonNativeTarget<FF>;
+
+    /// Returns `sum_i mask[i] * values[i]`, reduced once at the end via `sum_nonnative`.
+    fn masked_sum_nonnative<FF: PrimeField>(
+        &mut self,
+        values: &[NonNativeTarget<FF>],
+        mask: &[BoolTarget],
+    ) -> NonNativeTarget<FF>;
+
+    /// Returns `2 * x`, assuming `x` is reduced.
+    fn double_nonnative<FF: PrimeField>(&mut self, x: &NonNativeTarget<FF>) -> NonNativeTarget<FF>;
+
+    /// Returns `3 * x`, assuming `x` is reduced.
+    fn triple_nonnative<FF: PrimeField>(&mut self, x: &NonNativeTarget<FF>) -> NonNativeTarget<FF>;
+
+    /// Returns `x * scalar`, where `scalar` is given by its little-endian bits, using
+    /// double-and-add. An empty or all-zero scalar yields zero.
+    fn scalar_mul_nonnative<FF: PrimeField>(
+        &mut self,
+        x: &NonNativeTarget<FF>,
+        scalar_bits: &[BoolTarget],
+    ) -> NonNativeTarget<FF>;
+
     // Subtract two `NonNativeTarget`s.
     fn sub_nonnative<FF: PrimeField>(
         &mut self,
@@ -168,45 +377,174 @@ pub trait CircuitBuilderNonNative<F: RichField + Extendable<D>, const D: usize>
         b: &NonNativeTarget<FF>,
     ) -> NonNativeTarget<FF>;
 
+    /// Returns `base - sum(to_subtract)` using a single generator. The result is constrained by
+    /// `base + borrow * |FF| = result + sum(to_subtract)`, where `result < |FF|` and, since every
+    /// input is reduced, `borrow <= to_subtract.len()`.
+    fn sub_many_nonnative<FF: PrimeField>(
+        &mut self,
+        base: &NonNativeTarget<FF>,
+        to_subtract: &[NonNativeTarget<FF>],
+    ) -> NonNativeTarget<FF>;
+
     fn mul_nonnative<FF: PrimeField>(
         &mut self,
         a: &NonNativeTarget<FF>,
         b: &NonNativeTarget<FF>,
     ) -> NonNativeTarget<FF>;
 
+    /// Multiplies two `NonNativeTarget`s, returning the reduced result together with the full
+    /// double-width integer product `a * b`.
+    fn mul_nonnative_with_product<FF: PrimeField>(
+        &mut self,
+        a: &NonNativeTarget<FF>,
+        b: &NonNativeTarget<FF>,
+    ) -> (NonNativeTarget<FF>, BigUintTarget);
+
+    /// Multiplies two `NonNativeTarget`s, returning the reduced result together with the
+    /// quotient `floor(a * b / p)` witnessed by the multiplication generator.
+    fn mul_nonnative_with_overflow<FF: PrimeField>(
+        &mut self,
+        a: &NonNativeTarget<FF>,
+        b: &NonNativeTarget<FF>,
+    ) -> (NonNativeTarget<FF>, BigUintTarget);
+
     fn mul_many_nonnative<FF: PrimeField>(
         &mut self,
         to_mul: &[NonNativeTarget<FF>],
     ) -> NonNativeTarget<FF>;
 
+    /// Returns `sum_i a[i] * b[i]`. The integer products are summed unreduced and the total is
+    /// reduced once at the end.
+    fn inner_product_nonnative<FF: PrimeField>(
+        &mut self,
+        a: &[NonNativeTarget<FF>],
+        b: &[NonNativeTarget<FF>],
+    ) -> NonNativeTarget<FF>;
+
+    /// Multiplies a `NonNativeTarget` by a constant, which is cheaper than `mul_nonnative` with
+    /// a `constant_nonnative` operand.
+    fn mul_const_nonnative<FF: PrimeField>(
+        &mut self,
+        a: &NonNativeTarget<FF>,
+        c: FF,
+    ) -> NonNativeTarget<FF>;
+
     fn neg_nonnative<FF: PrimeField>(&mut self, x: &NonNativeTarget<FF>) -> NonNativeTarget<FF>;
 
     fn inv_nonnative<FF: PrimeField>(&mut self, x: &NonNativeTarget<FF>) -> NonNativeTarget<FF>;
 
+    /// Returns `x^(p - 2)`, which is the inverse of `x` for nonzero `x` and zero otherwise. Unlike
+    /// `inv_nonnative`, the result is computed from `x` by multiplications alone rather than
+    /// witnessed by an inverse hint.
+    fn inv_nonnative_fermat<FF: PrimeField>(
+        &mut self,
+        x: &NonNativeTarget<FF>,
+    ) -> NonNativeTarget<FF>;
+
     /// Returns `x % |FF|` as a `NonNativeTarget`.
     fn reduce<FF: PrimeField>(&mut self, x: &BigUintTarget) -> NonNativeTarget<FF>;
 
+    /// Returns `x % |FF|` for an input of up to `2 * FF::BITS` bits, such as a hash digest being
+    /// mapped into the field.
+    fn reduce_wide<FF: PrimeField>(&mut self, x: &BigUintTarget) -> NonNativeTarget<FF>;
+
     fn reduce_nonnative<FF: PrimeField>(&mut self, x: &NonNativeTarget<FF>) -> NonNativeTarget<FF>;
 
+    /// Reduces `x`, which must be below `bound * |FF|`, by witnessing the quotient `x / |FF|` in
+    /// binary and subtracting the matching multiples `2^i * |FF|`. Costs `log2_ceil(bound)`
+    /// conditional subtractions rather than the general division of `reduce_nonnative`.
+    fn to_canonical_nonnative<FF: PrimeField>(
+        &mut self,
+        x: &NonNativeTarget<FF>,
+        bound: usize,
+    ) -> NonNativeTarget<FF>;
+
     fn bool_to_nonnative<FF: PrimeField>(&mut self, b: &BoolTarget) -> NonNativeTarget<FF>;
 
-    // Split a nonnative field element to bits.
+    // Split a nonnative field element to bits. Each limb is decomposed with a base-2 `BaseSumGate`,
+    // which constrains every bit to be boolean and the bits to recombine to the limb exactly.
     fn split_nonnative_to_bits<FF: PrimeField>(
         &mut self,
         x: &NonNativeTarget<FF>,
     ) -> Vec<BoolTarget>;
 
+    /// Like `split_nonnative_to_bits`, but also asserts that `x < |FF|`, so the bits are the
+    /// canonical encoding of a field element. Returns only the low `FF::BITS` bits, since the rest
+    /// are then zero.
+    fn split_nonnative_to_bits_canonical<FF: PrimeField>(
+        &mut self,
+        x: &NonNativeTarget<FF>,
+    ) -> Vec<BoolTarget>;
+
     fn nonnative_conditional_neg<FF: PrimeField>(
         &mut self,
         x: &NonNativeTarget<FF>,
         b: BoolTarget,
     ) -> NonNativeTarget<FF>;
 
+    /// Returns `v[access_index]`. Every entry must have `num_nonnative_limbs::<FF>()` limbs, and
+    /// `access_index` is constrained to be less than `v.len()`.
     fn random_access_nonnative<FF: PrimeField>(
         &mut self,
         access_index: Target,
         v: Vec<NonNativeTarget<FF>>,
     ) -> NonNativeTarget<FF>;
+
+    /// Returns `x^exponent` for a constant `exponent`, using square-and-multiply.
+    fn pow_const_nonnative<FF: PrimeField>(
+        &mut self,
+        x: &NonNativeTarget<FF>,
+        exponent: &BigUint,
+    ) -> NonNativeTarget<FF>;
+
+    /// Returns whether `x` is a nonzero quadratic residue modulo `|FF|`.
+    fn is_quadratic_residue_nonnative<FF: PrimeField>(
+        &mut self,
+        x: &NonNativeTarget<FF>,
+    ) -> BoolTarget;
+
+    /// Returns whether `x` is a perfect square modulo `|FF|`. Zero is considered a square.
+    fn is_square_nonnative<FF: PrimeField>(&mut self, x: &NonNativeTarget<FF>) -> BoolTarget;
+
+    /// Returns `x * x`; the product limbs are only range-checked, not reduced.
+    fn square_nonnative<FF: PrimeField>(&mut self, x: &NonNativeTarget<FF>) -> NonNativeTarget<FF>;
+
+    /// Returns a square root of `x` modulo `|FF|`, together with a flag indicating whether one
+    /// exists. If `x` is not a square, the returned root is zero.
+    fn sqrt_nonnative<FF: PrimeField>(
+        &mut self,
+        x: &NonNativeTarget<FF>,
+    ) -> (NonNativeTarget<FF>, BoolTarget);
+
+    /// Reduces `x` modulo `|FF|` into balanced form, returning the magnitude together with a sign
+    /// bit. Residues `r > (|FF| - 1) / 2` are reported as negative with magnitude `|FF| - r`.
+    fn reduce_balanced<FF: PrimeField>(
+        &mut self,
+        x: &BigUintTarget,
+    ) -> (NonNativeTarget<FF>, BoolTarget);
+
+    /// Returns `x % modulus` for a small nonzero constant `modulus`.
+    fn reduce_mod_small_prime<FF: PrimeField>(
+        &mut self,
+        x: &NonNativeTarget<FF>,
+        modulus: u32,
+    ) -> U32Target;
+
+    /// Reconstructs the element with the given `(modulus, residue)` pairs via the Chinese
+    /// remainder theorem. The moduli must be pairwise coprime, and the result is constrained to
+    /// be less than both their product and `|FF|`, which makes it unique.
+    fn crt_reconstruct<FF: PrimeField>(
+        &mut self,
+        residues: &[(u32, U32Target)],
+    ) -> NonNativeTarget<FF>;
+
+    /// Asserts that `g` generates the multiplicative group of `FF`, given the distinct prime
+    /// factors of `|FF| - 1`.
+    fn assert_is_generator<FF: PrimeField>(
+        &mut self,
+        g: &NonNativeTarget<FF>,
+        prime_factors_of_p_minus_1: &[BigUint],
+    );
 }
 
 impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderNonNative<F, D>
@@ -231,23 +569,42 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderNonNative<F, D>
     }
 
     fn constant_nonnative<FF: PrimeField>(&mut self, x: FF) -> NonNativeTarget<FF> {
-        let x_biguint = self.constant_biguint(&x.to_canonical_biguint());
-        self.biguint_to_nonnative(&x_biguint)
+        // Zero-pad the high limbs so constants have the same shape as virtual targets.
+        let mut limb_values = x.to_canonical_biguint().to_u32_digits();
+        limb_values.resize(Self::num_nonnative_limbs::<FF>(), 0);
+        let limbs = limb_values.iter().map(|&l| self.constant_u32(l)).collect();
+
+        self.biguint_to_nonnative(&BigUintTarget { limbs })
     }
 
     fn zero_nonnative<FF: PrimeField>(&mut self) -> NonNativeTarget<FF> {
         self.constant_nonnative(FF::ZERO)
     }
 
+    fn nonnative_modulus<FF: PrimeField>(&mut self) -> BigUintTarget {
+        self.constant_biguint(&FF::order())
+    }
+
     // Assert that two NonNativeTarget's, both assumed to be in reduced form, are equal.
     fn connect_nonnative<FF: PrimeField>(
         &mut self,
         lhs: &NonNativeTarget<FF>,
         rhs: &NonNativeTarget<FF>,
     ) {
+        #[cfg(feature = "nonnative_debug_assertions")]
+        {
+            self.assert_nonnative_reduced(lhs);
+            self.assert_nonnative_reduced(rhs);
+        }
         self.connect_biguint(&lhs.value, &rhs.value);
     }
 
+    fn assert_nonnative_reduced<FF: PrimeField>(&mut self, x: &NonNativeTarget<FF>) {
+        let max = self.constant_biguint(&(FF::order() - BigUint::one()));
+        let cmp = self.cmp_biguint(&x.value, &max);
+        self.assert_one(cmp.target);
+    }
+
     fn add_virtual_nonnative_target<FF: PrimeField>(&mut self) -> NonNativeTarget<FF> {
         let num_limbs = Self::num_nonnative_limbs::<FF>();
         let value = self.add_virtual_biguint_target(num_limbs);
@@ -258,6 +615,13 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderNonNative<F, D>
         }
     }
 
+    fn add_virtual_nonnative_target_checked<FF: PrimeField>(&mut self) -> NonNativeTarget<FF> {
+        let x = self.add_virtual_nonnative_target::<FF>();
+        range_check_u32_circuit(self, x.value.limbs.clone());
+        self.assert_nonnative_reduced(&x);
+        x
+    }
+
     fn add_virtual_nonnative_target_sized<FF: PrimeField>(
         &mut self,
         num_limbs: usize,
@@ -270,6 +634,12 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderNonNative<F, D>
         }
     }
 
+    fn register_nonnative_public_input<FF: PrimeField>(&mut self, x: &NonNativeTarget<FF>) {
+        assert_eq!(x.value.num_limbs(), Self::num_nonnative_limbs::<FF>());
+        let limbs = x.value.limbs.iter().map(|l| l.0).collect::<Vec<_>>();
+        self.register_public_inputs(&limbs);
+    }
+
     fn add_nonnative<FF: PrimeField>(
         &mut self,
         a: &NonNativeTarget<FF>,
@@ -288,7 +658,7 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderNonNative<F, D>
 
         let sum_expected = self.add_biguint(&a.value, &b.value);
 
-        let modulus = self.constant_biguint(&FF::order());
+        let modulus = self.nonnative_modulus::<FF>();
         let mod_times_overflow = self.mul_biguint_by_bool(&modulus, overflow);
         let sum_actual = self.add_biguint(&sum.value, &mod_times_overflow);
         self.connect_biguint(&sum_expected, &sum_actual);
@@ -302,6 +672,35 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderNonNative<F, D>
         sum
     }
 
+    fn add_nonnative_no_reduce<FF: PrimeField>(
+        &mut self,
+        a: &NonNativeTarget<FF>,
+        b: &NonNativeTarget<FF>,
+    ) -> NonNativeTarget<FF> {
+        let sum = self.add_virtual_nonnative_target::<FF>();
+        let overflow = self.add_virtual_bool_target_safe();
+
+        self.add_simple_generator(NonNativeAdditionGenerator::<F, D, FF> {
+            a: a.clone(),
+            b: b.clone(),
+            sum: sum.clone(),
+            overflow,
+            _phantom: PhantomData,
+        });
+
+        // Without the comparison against the modulus, the limbs need their own range check.
+        range_check_u32_circuit(self, sum.value.limbs.clone());
+
+        let sum_expected = self.add_biguint(&a.value, &b.value);
+
+        let modulus = self.nonnative_modulus::<FF>();
+        let mod_times_overflow = self.mul_biguint_by_bool(&modulus, overflow);
+        let sum_actual = self.add_biguint(&sum.value, &mod_times_overflow);
+        self.connect_biguint(&sum_expected, &sum_actual);
+
+        sum
+    }
+
     fn mul_nonnative_by_bool<FF: PrimeField>(
         &mut self,
         a: &NonNativeTarget<FF>,
@@ -319,10 +718,35 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderNonNative<F, D>
         x: &NonNativeTarget<FF>,
         y: &NonNativeTarget<FF>,
     ) -> NonNativeTarget<FF> {
-        let not_b = self.not(b);
-        let maybe_x = self.mul_nonnative_by_bool(x, b);
-        let maybe_y = self.mul_nonnative_by_bool(y, not_b);
-        self.add_nonnative(&maybe_x, &maybe_y)
+        // Only one of the branches is ever selected, so the result can be chosen limb by limb,
+        // without the carries and reduction that `add_nonnative` would introduce.
+        let num_limbs = x.value.num_limbs().max(y.value.num_limbs());
+        let zero = self.zero_u32();
+        let limbs = (0..num_limbs)
+            .map(|i| {
+                let x_limb = x.value.limbs.get(i).copied().unwrap_or(zero);
+                let y_limb = y.value.limbs.get(i).copied().unwrap_or(zero);
+                U32Target(self.select(b, x_limb.0, y_limb.0))
+            })
+            .collect();
+
+        NonNativeTarget {
+            value: BigUintTarget { limbs },
+            _phantom: PhantomData,
+        }
+    }
+
+    fn select_nonnative<FF: PrimeField>(
+        &mut self,
+        b: BoolTarget,
+        x: &NonNativeTarget<FF>,
+        y: &NonNativeTarget<FF>,
+    ) -> NonNativeTarget<FF> {
+        match self.target_as_constant(b.target) {
+            Some(c) if c == F::ONE => x.clone(),
+            Some(_) => y.clone(),
+            None => self.if_nonnative(b, x, y),
+        }
     }
 
     fn add_many_nonnative<FF: PrimeField>(
@@ -351,7 +775,7 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderNonNative<F, D>
             .iter()
             .fold(self.zero_biguint(), |a, b| self.add_biguint(&a, &b.value));
 
-        let modulus = self.constant_biguint(&FF::order());
+        let modulus = self.nonnative_modulus::<FF>();
         let overflow_biguint = BigUintTarget {
             limbs: vec![overflow],
         };
@@ -368,6 +792,85 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderNonNative<F, D>
         sum
     }
 
+    fn sum_nonnative<FF: PrimeField>(
+        &mut self,
+        summands: &[NonNativeTarget<FF>],
+    ) -> NonNativeTarget<FF> {
+        match summands.len() {
+            0 => return self.zero_nonnative(),
+            1 => return summands[0].clone(),
+            _ => {}
+        }
+
+        let sum = self.add_virtual_nonnative_target::<FF>();
+        let overflow_limbs = ceil_div_usize(log2_ceil(summands.len()), 32);
+        let overflow = self.add_virtual_biguint_target(overflow_limbs);
+
+        self.add_simple_generator(NonNativeSumGenerator::<F, D, FF> {
+            summands: summands.to_vec(),
+            sum: sum.clone(),
+            overflow: overflow.clone(),
+            _phantom: PhantomData,
+        });
+
+        range_check_u32_circuit(self, sum.value.limbs.clone());
+        range_check_u32_circuit(self, overflow.limbs.clone());
+
+        // The sum is below `summands.len() * |FF|`, so it fits in `num_limbs + overflow_limbs`
+        // limbs. Truncating the carry limb of each addition keeps the running sum at that width.
+        let sum_limbs = Self::num_nonnative_limbs::<FF>() + overflow_limbs;
+        let sum_expected = summands.iter().fold(self.zero_biguint(), |a, b| {
+            let sum = self.add_biguint(&a, &b.value);
+            truncate_biguint(self, &sum, sum_limbs)
+        });
+
+        let modulus = self.nonnative_modulus::<FF>();
+        let mod_times_overflow = self.mul_biguint(&modulus, &overflow);
+        let sum_actual = self.add_biguint(&sum.value, &mod_times_overflow);
+        self.connect_biguint(&sum_expected, &sum_actual);
+
+        self.assert_nonnative_reduced(&sum);
+
+        sum
+    }
+
+    fn masked_sum_nonnative<FF: PrimeField>(
+        &mut self,
+        values: &[NonNativeTarget<FF>],
+        mask: &[BoolTarget],
+    ) -> NonNativeTarget<FF> {
+        assert_eq!(values.len(), mask.len());
+        let masked = values
+            .iter()
+            .zip(mask.iter())
+            .map(|(x, &b)| self.mul_nonnative_by_bool(x, b))
+            .collect::<Vec<_>>();
+        self.sum_nonnative(&masked)
+    }
+
+    fn double_nonnative<FF: PrimeField>(&mut self, x: &NonNativeTarget<FF>) -> NonNativeTarget<FF> {
+        small_multiple_nonnative(self, x, 2)
+    }
+
+    fn triple_nonnative<FF: PrimeField>(&mut self, x: &NonNativeTarget<FF>) -> NonNativeTarget<FF> {
+        small_multiple_nonnative(self, x, 3)
+    }
+
+    fn scalar_mul_nonnative<FF: PrimeField>(
+        &mut self,
+        x: &NonNativeTarget<FF>,
+        scalar_bits: &[BoolTarget],
+    ) -> NonNativeTarget<FF> {
+        // Scan the scalar from its most significant bit.
+        let mut result = self.zero_nonnative();
+        for &bit in scalar_bits.iter().rev() {
+            result = self.double_nonnative(&result);
+            let maybe_x = self.mul_nonnative_by_bool(x, bit);
+            result = self.add_nonnative(&result, &maybe_x);
+        }
+        result
+    }
+
     // Subtract two `NonNativeTarget`s.
     fn sub_nonnative<FF: PrimeField>(
         &mut self,
@@ -389,7 +892,7 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderNonNative<F, D>
         self.assert_bool(overflow);
 
         let diff_plus_b = self.add_biguint(&diff.value, &b.value);
-        let modulus = self.constant_biguint(&FF::order());
+        let modulus = self.nonnative_modulus::<FF>();
         let mod_times_overflow = self.mul_biguint_by_bool(&modulus, overflow);
         let diff_plus_b_reduced = self.sub_biguint(&diff_plus_b, &mod_times_overflow);
         self.connect_biguint(&a.value, &diff_plus_b_reduced);
@@ -397,57 +900,164 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderNonNative<F, D>
         diff
     }
 
-    fn mul_nonnative<FF: PrimeField>(
+    fn sub_many_nonnative<FF: PrimeField>(
         &mut self,
-        a: &NonNativeTarget<FF>,
-        b: &NonNativeTarget<FF>,
+        base: &NonNativeTarget<FF>,
+        to_subtract: &[NonNativeTarget<FF>],
     ) -> NonNativeTarget<FF> {
-        let prod = self.add_virtual_nonnative_target::<FF>();
-        let modulus = self.constant_biguint(&FF::order());
-        let overflow = self.add_virtual_biguint_target(
-            a.value.num_limbs() + b.value.num_limbs() - modulus.num_limbs(),
-        );
+        if to_subtract.is_empty() {
+            return base.clone();
+        }
 
-        self.add_simple_generator(NonNativeMultiplicationGenerator::<F, D, FF> {
-            a: a.clone(),
-            b: b.clone(),
-            prod: prod.clone(),
-            overflow: overflow.clone(),
+        let result = self.add_virtual_nonnative_target::<FF>();
+        let borrow =
+            self.add_virtual_biguint_target(ceil_div_usize(log2_ceil(to_subtract.len() + 1), 32));
+
+        self.add_simple_generator(NonNativeMultipleSubsGenerator::<F, D, FF> {
+            base: base.clone(),
+            to_subtract: to_subtract.to_vec(),
+            result: result.clone(),
+            borrow: borrow.clone(),
             _phantom: PhantomData,
         });
 
-        range_check_u32_circuit(self, prod.value.limbs.clone());
-        range_check_u32_circuit(self, overflow.limbs.clone());
+        range_check_u32_circuit(self, result.value.limbs.clone());
+        range_check_u32_circuit(self, borrow.limbs.clone());
 
-        let prod_expected = self.mul_biguint(&a.value, &b.value);
+        let modulus = self.nonnative_modulus::<FF>();
+        let mod_times_borrow = self.mul_biguint(&modulus, &borrow);
+        let lhs = self.add_biguint(&base.value, &mod_times_borrow);
+        let rhs = to_subtract.iter().fold(result.value.clone(), |acc, x| {
+            self.add_biguint(&acc, &x.value)
+        });
+        self.connect_biguint(&lhs, &rhs);
 
-        let mod_times_overflow = self.mul_biguint(&modulus, &overflow);
-        let prod_actual = self.add_biguint(&prod.value, &mod_times_overflow);
-        self.connect_biguint(&prod_expected, &prod_actual);
+        self.assert_nonnative_reduced(&result);
 
-        prod
+        result
     }
 
-    fn mul_many_nonnative<FF: PrimeField>(
+    fn mul_nonnative<FF: PrimeField>(
         &mut self,
-        to_mul: &[NonNativeTarget<FF>],
+        a: &NonNativeTarget<FF>,
+        b: &NonNativeTarget<FF>,
     ) -> NonNativeTarget<FF> {
-        if to_mul.len() == 1 {
-            return to_mul[0].clone();
-        }
-
-        let mut accumulator = self.mul_nonnative(&to_mul[0], &to_mul[1]);
-        for t in to_mul.iter().skip(2) {
-            accumulator = self.mul_nonnative(&accumulator, t);
-        }
-        accumulator
+        self.mul_nonnative_with_product(a, b).0
     }
 
-    fn neg_nonnative<FF: PrimeField>(&mut self, x: &NonNativeTarget<FF>) -> NonNativeTarget<FF> {
-        let zero_target = self.constant_biguint(&BigUint::zero());
-        let zero_ff = self.biguint_to_nonnative(&zero_target);
-
-        self.sub_nonnative(&zero_ff, x)
+    fn mul_nonnative_with_product<FF: PrimeField>(
+        &mut self,
+        a: &NonNativeTarget<FF>,
+        b: &NonNativeTarget<FF>,
+    ) -> (NonNativeTarget<FF>, BigUintTarget) {
+        let (prod, product, _overflow) = mul_nonnative_parts(self, a, b);
+        (prod, product)
+    }
+
+    fn mul_nonnative_with_overflow<FF: PrimeField>(
+        &mut self,
+        a: &NonNativeTarget<FF>,
+        b: &NonNativeTarget<FF>,
+    ) -> (NonNativeTarget<FF>, BigUintTarget) {
+        let (prod, _product, overflow) = mul_nonnative_parts(self, a, b);
+        (prod, overflow)
+    }
+
+    fn mul_many_nonnative<FF: PrimeField>(
+        &mut self,
+        to_mul: &[NonNativeTarget<FF>],
+    ) -> NonNativeTarget<FF> {
+        if to_mul.len() == 1 {
+            return to_mul[0].clone();
+        }
+
+        let mut accumulator = self.mul_nonnative(&to_mul[0], &to_mul[1]);
+        for t in to_mul.iter().skip(2) {
+            accumulator = self.mul_nonnative(&accumulator, t);
+        }
+        accumulator
+    }
+
+    fn inner_product_nonnative<FF: PrimeField>(
+        &mut self,
+        a: &[NonNativeTarget<FF>],
+        b: &[NonNativeTarget<FF>],
+    ) -> NonNativeTarget<FF> {
+        assert_eq!(a.len(), b.len());
+
+        // Each product is below `2^(64 * num_limbs)`, so the sum of `a.len()` of them fits in
+        // `2 * num_limbs + ceil(log2(a.len()) / 32)` limbs. The accumulator is kept at that width
+        // plus the carry limb of the next addition, rather than growing by one limb per term.
+        let num_limbs = num_nonnative_limbs::<FF>();
+        let sum_limbs = 2 * num_limbs + ceil_div_usize(log2_ceil(a.len()), 32) + 1;
+        let sum = a
+            .iter()
+            .zip(b.iter())
+            .fold(self.zero_biguint(), |sum, (x, y)| {
+                let product = self.mul_biguint(&x.value, &y.value);
+                let sum = self.add_biguint(&sum, &product);
+                truncate_biguint(self, &sum, sum_limbs)
+            });
+        self.reduce(&sum)
+    }
+
+    fn mul_const_nonnative<FF: PrimeField>(
+        &mut self,
+        a: &NonNativeTarget<FF>,
+        c: FF,
+    ) -> NonNativeTarget<FF> {
+        if c == FF::ZERO {
+            return self.zero_nonnative();
+        }
+        if c == FF::ONE {
+            return a.clone();
+        }
+
+        let c_biguint = c.to_canonical_biguint();
+        let c_target = self.constant_nonnative(c);
+        let prod = self.add_virtual_nonnative_target::<FF>();
+        let modulus = self.nonnative_modulus::<FF>();
+        // Since `a` is reduced, the quotient `a * c / |FF|` is less than `c`.
+        let overflow = self.add_virtual_biguint_target(c_biguint.to_u32_digits().len());
+
+        self.add_simple_generator(NonNativeMultiplicationGenerator::<F, D, FF> {
+            a: a.clone(),
+            b: c_target,
+            prod: prod.clone(),
+            overflow: overflow.clone(),
+            _phantom: PhantomData,
+        });
+
+        range_check_u32_circuit(self, prod.value.limbs.clone());
+        range_check_u32_circuit(self, overflow.limbs.clone());
+
+        let prod_expected = self.mul_biguint_by_constant(&a.value, &c_biguint);
+
+        let mod_times_overflow = self.mul_biguint(&modulus, &overflow);
+        let prod_actual = self.add_biguint(&prod.value, &mod_times_overflow);
+        self.connect_biguint(&prod_expected, &prod_actual);
+
+        prod
+    }
+
+    fn neg_nonnative<FF: PrimeField>(&mut self, x: &NonNativeTarget<FF>) -> NonNativeTarget<FF> {
+        // For reduced `x`, `|FF| - x` never borrows. The only case needing care is `x == 0`,
+        // whose negation is zero rather than `|FF|`.
+        let modulus = self.nonnative_modulus::<FF>();
+        let diff = self.sub_biguint(&modulus, &x.value);
+
+        let zero = self.zero_biguint();
+        let is_zero = self.is_equal_biguint(&x.value, &zero);
+        let is_nonzero = self.not(is_zero);
+        let neg = self.mul_biguint_by_bool(&diff, is_nonzero);
+
+        // `sub_biguint` leaves its final borrow unconstrained, so an unreduced `x` would wrap.
+        // Requiring `neg + x` to be exactly `|FF|` or zero rules that out.
+        let sum = self.add_biguint(&neg, &x.value);
+        let expected_sum = self.mul_biguint_by_bool(&modulus, is_nonzero);
+        self.connect_biguint(&sum, &expected_sum);
+
+        self.biguint_to_nonnative(&neg)
     }
 
     fn inv_nonnative<FF: PrimeField>(&mut self, x: &NonNativeTarget<FF>) -> NonNativeTarget<FF> {
@@ -464,7 +1074,7 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderNonNative<F, D>
 
         let product = self.mul_biguint(&x.value, &inv_biguint);
 
-        let modulus = self.constant_biguint(&FF::order());
+        let modulus = self.nonnative_modulus::<FF>();
         let mod_times_div = self.mul_biguint(&modulus, &div);
         let one = self.constant_biguint(&BigUint::one());
         let expected_product = self.add_biguint(&mod_times_div, &one);
@@ -476,14 +1086,84 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderNonNative<F, D>
         }
     }
 
+    fn inv_nonnative_fermat<FF: PrimeField>(
+        &mut self,
+        x: &NonNativeTarget<FF>,
+    ) -> NonNativeTarget<FF> {
+        let exponent = FF::order() - 2u32;
+        let inv = self.pow_const_nonnative(x, &exponent);
+        // The product limbs are only range-checked, so reduce to pin down a unique result.
+        self.reduce_nonnative(&inv)
+    }
+
     /// Returns `x % |FF|` as a `NonNativeTarget`.
     fn reduce<FF: PrimeField>(&mut self, x: &BigUintTarget) -> NonNativeTarget<FF> {
         let modulus = FF::order();
-        let order_target = self.constant_biguint(&modulus);
-        let value = self.rem_biguint(x, &order_target);
+        let num_limbs = Self::num_nonnative_limbs::<FF>();
+        let x_limbs = x.num_limbs();
+
+        // `|FF| > 2^(32 * (num_limbs - 1))`, so narrower values are already reduced.
+        if x_limbs < num_limbs {
+            let mut limbs = x.limbs.clone();
+            limbs.resize(num_limbs, self.zero_u32());
+            return NonNativeTarget {
+                value: BigUintTarget { limbs },
+                _phantom: PhantomData,
+            };
+        }
+
+        // Barrett reduction with `k = 32 * x_limbs`, so that `x < 2^k`. Since the modulus is a
+        // constant, `mu = floor(2^k / |FF|)` is precomputed and the quotient estimate
+        // `floor(x * mu / 2^k)` is either the true quotient or one less than it.
+        let mu = (BigUint::one() << (32 * x_limbs)) / &modulus;
+        let x_mu = self.mul_biguint_by_constant(x, &mu);
+        let quotient = BigUintTarget {
+            limbs: x_mu.limbs[x_limbs..].to_vec(),
+        };
+        let quotient_times_modulus = self.mul_biguint_by_constant(&quotient, &modulus);
+        let estimate = self.sub_biguint(x, &quotient_times_modulus);
+
+        // The estimate lies in `[0, 2 * |FF|)`, so one conditional subtraction finishes the job.
+        let modulus_minus_one = self.constant_biguint(&(&modulus - BigUint::one()));
+        let is_reduced = self.cmp_biguint(&estimate, &modulus_minus_one);
+        let is_off_by_one = self.not(is_reduced);
+        let modulus_target = self.nonnative_modulus::<FF>();
+        let correction = self.mul_biguint_by_bool(&modulus_target, is_off_by_one);
+        let reduced = self.sub_biguint(&estimate, &correction);
+
+        for &limb in reduced.limbs[num_limbs..].iter() {
+            self.assert_zero_u32(limb);
+        }
 
         NonNativeTarget {
-            value,
+            value: BigUintTarget {
+                limbs: reduced.limbs[..num_limbs].to_vec(),
+            },
+            _phantom: PhantomData,
+        }
+    }
+
+    fn reduce_wide<FF: PrimeField>(&mut self, x: &BigUintTarget) -> NonNativeTarget<FF> {
+        let num_limbs = Self::num_nonnative_limbs::<FF>();
+        let max_limbs = ceil_div_usize(2 * FF::BITS, 32);
+        assert!(
+            x.num_limbs() <= max_limbs,
+            "reduce_wide: input has {} limbs but at most {} are supported",
+            x.num_limbs(),
+            max_limbs
+        );
+        if x.num_limbs() < num_limbs {
+            return self.reduce(x);
+        }
+
+        // `x < 2^(32 * x.num_limbs())` and `|FF| > 2^(32 * (num_limbs - 1))`, which bounds the
+        // number of quotient limbs.
+        let quotient_limbs = x.num_limbs() - num_limbs + 1;
+        let modulus = self.nonnative_modulus::<FF>();
+        let (_, rem) = self._div_rem_biguint(x, &modulus, quotient_limbs);
+
+        NonNativeTarget {
+            value: rem,
             _phantom: PhantomData,
         }
     }
@@ -493,6 +1173,42 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderNonNative<F, D>
         self.reduce(&x_biguint)
     }
 
+    fn to_canonical_nonnative<FF: PrimeField>(
+        &mut self,
+        x: &NonNativeTarget<FF>,
+        bound: usize,
+    ) -> NonNativeTarget<FF> {
+        assert!(bound > 0, "bound must be positive");
+
+        let result = self.add_virtual_nonnative_target::<FF>();
+        let quotient_bits = (0..log2_ceil(bound))
+            .map(|_| self.add_virtual_bool_target_safe())
+            .collect::<Vec<_>>();
+
+        self.add_simple_generator(NonNativeCanonicalGenerator::<F, D, FF> {
+            x: x.clone(),
+            result: result.clone(),
+            quotient_bits: quotient_bits.clone(),
+            _phantom: PhantomData,
+        });
+
+        range_check_u32_circuit(self, result.value.limbs.clone());
+
+        let actual = quotient_bits
+            .iter()
+            .enumerate()
+            .fold(result.value.clone(), |acc, (i, &b)| {
+                let multiple = self.constant_biguint(&(FF::order() << i));
+                let multiple_times_b = self.mul_biguint_by_bool(&multiple, b);
+                self.add_biguint(&acc, &multiple_times_b)
+            });
+        self.connect_biguint(&x.value, &actual);
+
+        self.assert_nonnative_reduced(&result);
+
+        result
+    }
+
     fn bool_to_nonnative<FF: PrimeField>(&mut self, b: &BoolTarget) -> NonNativeTarget<FF> {
         let limbs = vec![U32Target(b.target)];
         let value = BigUintTarget { limbs };
@@ -525,6 +1241,22 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderNonNative<F, D>
         result
     }
 
+    fn split_nonnative_to_bits_canonical<FF: PrimeField>(
+        &mut self,
+        x: &NonNativeTarget<FF>,
+    ) -> Vec<BoolTarget> {
+        let max = self.constant_biguint(&(FF::order() - BigUint::one()));
+        let is_reduced = self.cmp_biguint(&x.value, &max);
+        self.assert_one(is_reduced.target);
+
+        let mut bits = self.split_nonnative_to_bits(x);
+        for bit in bits.iter().skip(FF::BITS) {
+            self.assert_zero(bit.target);
+        }
+        bits.truncate(FF::BITS);
+        bits
+    }
+
     fn nonnative_conditional_neg<FF: PrimeField>(
         &mut self,
         x: &NonNativeTarget<FF>,
@@ -543,6 +1275,23 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderNonNative<F, D>
         access_index: Target,
         v: Vec<NonNativeTarget<FF>>,
     ) -> NonNativeTarget<FF> {
+        let num_limbs = Self::num_nonnative_limbs::<FF>();
+        assert!(!v.is_empty(), "cannot access an empty vector");
+        for x in v.iter() {
+            assert_eq!(x.value.num_limbs(), num_limbs);
+        }
+
+        // The random access gate only supports power-of-two lengths and bounds the index by the
+        // padded length, so we bound it by the original length ourselves.
+        let len = v.len();
+        let mut v = v;
+        if !len.is_power_of_two() {
+            let max_index = self.constant(F::from_canonical_usize(len - 1));
+            let diff = self.sub(max_index, access_index);
+            self.range_check(diff, log2_ceil(len));
+            v.resize(len.next_power_of_two(), self.zero_nonnative());
+        }
+
         let selected = self
             .random_access_biguint(access_index, v.iter().map(|x| &x.value).collect::<Vec<_>>());
         NonNativeTarget {
@@ -550,6 +1299,240 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderNonNative<F, D>
             _phantom: PhantomData,
         }
     }
+
+    /// Returns `x^exponent` for a constant `exponent`, using square-and-multiply.
+    fn pow_const_nonnative<FF: PrimeField>(
+        &mut self,
+        x: &NonNativeTarget<FF>,
+        exponent: &BigUint,
+    ) -> NonNativeTarget<FF> {
+        if exponent.is_zero() {
+            return self.constant_nonnative(FF::ONE);
+        }
+
+        // Scan the exponent from its most significant bit, which is always set.
+        let mut result = x.clone();
+        for i in (0..exponent.bits() - 1).rev() {
+            result = self.mul_nonnative(&result, &result);
+            if exponent.bit(i) {
+                result = self.mul_nonnative(&result, x);
+            }
+        }
+        result
+    }
+
+    /// Returns whether `x` is a nonzero quadratic residue modulo `|FF|`.
+    fn is_quadratic_residue_nonnative<FF: PrimeField>(
+        &mut self,
+        x: &NonNativeTarget<FF>,
+    ) -> BoolTarget {
+        // By Euler's criterion, the Legendre symbol `x^((p - 1) / 2)` is one for residues, `p - 1`
+        // for non-residues and zero for zero, so only residues compare equal to one.
+        let exponent = (FF::order() - BigUint::one()) >> 1;
+        let legendre = self.pow_const_nonnative(x, &exponent);
+        // The product limbs are only range-checked, so reduce before comparing against one.
+        let legendre = self.reduce_nonnative(&legendre);
+        let one = self.constant_biguint(&BigUint::one());
+        self.is_equal_biguint(&legendre.value, &one)
+    }
+
+    /// Returns whether `x` is a perfect square modulo `|FF|`. Zero is considered a square.
+    fn is_square_nonnative<FF: PrimeField>(&mut self, x: &NonNativeTarget<FF>) -> BoolTarget {
+        let is_residue = self.is_quadratic_residue_nonnative(x);
+        let zero = self.zero_biguint();
+        let is_zero = self.is_equal_biguint(&x.value, &zero);
+
+        self.or(is_residue, is_zero)
+    }
+
+    fn square_nonnative<FF: PrimeField>(&mut self, x: &NonNativeTarget<FF>) -> NonNativeTarget<FF> {
+        self.mul_nonnative(x, x)
+    }
+
+    /// Returns a square root of `x` modulo `|FF|`, together with a flag indicating whether one
+    /// exists. If `x` is not a square, the returned root is zero.
+    fn sqrt_nonnative<FF: PrimeField>(
+        &mut self,
+        x: &NonNativeTarget<FF>,
+    ) -> (NonNativeTarget<FF>, BoolTarget) {
+        let root = self.add_virtual_nonnative_target::<FF>();
+        let exists = self.add_virtual_bool_target_unsafe();
+
+        self.add_simple_generator(NonNativeSqrtGenerator::<F, D, FF> {
+            x: x.clone(),
+            root: root.clone(),
+            exists,
+            _phantom: PhantomData,
+        });
+
+        range_check_u32_circuit(self, root.value.limbs.clone());
+        self.assert_bool(exists);
+
+        // The flag is pinned down by Euler's criterion, so a prover cannot claim that a square
+        // has no root.
+        let is_square = self.is_square_nonnative(x);
+        self.connect(exists.target, is_square.target);
+
+        // If a root exists, `root^2 == x`. Otherwise the root is zero and so is `root^2`.
+        let root_squared = self.square_nonnative(&root);
+        let x_if_exists = self.mul_nonnative_by_bool(x, exists);
+        self.connect_nonnative(&root_squared, &x_if_exists);
+
+        (root, exists)
+    }
+
+    /// Reduces `x` modulo `|FF|` into balanced form, returning the magnitude together with a sign
+    /// bit. Residues `r > (|FF| - 1) / 2` are reported as negative with magnitude `|FF| - r`.
+    fn reduce_balanced<FF: PrimeField>(
+        &mut self,
+        x: &BigUintTarget,
+    ) -> (NonNativeTarget<FF>, BoolTarget) {
+        let reduced = self.reduce::<FF>(x);
+
+        let half = self.constant_biguint(&((FF::order() - BigUint::one()) >> 1));
+        let is_non_negative = self.cmp_biguint(&reduced.value, &half);
+        let is_negative = self.not(is_non_negative);
+
+        // A negative residue is nonzero, so negating it never wraps around to `|FF|`.
+        let negated = self.neg_nonnative(&reduced);
+        let magnitude = self.if_nonnative(is_negative, &negated, &reduced);
+
+        (magnitude, is_negative)
+    }
+
+    fn reduce_mod_small_prime<FF: PrimeField>(
+        &mut self,
+        x: &NonNativeTarget<FF>,
+        modulus: u32,
+    ) -> U32Target {
+        assert!(modulus != 0, "cannot reduce modulo zero");
+        let modulus = self.constant_biguint(&BigUint::from(modulus));
+        let (_, rem) = self.div_rem_biguint(&x.value, &modulus);
+
+        rem.get_limb(0)
+    }
+
+    fn crt_reconstruct<FF: PrimeField>(
+        &mut self,
+        residues: &[(u32, U32Target)],
+    ) -> NonNativeTarget<FF> {
+        for (i, &(m_i, _)) in residues.iter().enumerate() {
+            for &(m_j, _) in &residues[..i] {
+                assert_eq!(m_i.gcd(&m_j), 1, "CRT moduli must be pairwise coprime");
+            }
+        }
+
+        let x = self.add_virtual_nonnative_target::<FF>();
+        self.add_simple_generator(NonNativeCrtGenerator::<F, D, FF> {
+            residues: residues.to_vec(),
+            x: x.clone(),
+            _phantom: PhantomData,
+        });
+        range_check_u32_circuit(self, x.value.limbs.clone());
+
+        let product = residues
+            .iter()
+            .map(|&(m, _)| BigUint::from(m))
+            .product::<BigUint>();
+        let bound = self.constant_biguint(&(product.min(FF::order()) - BigUint::one()));
+        let in_range = self.cmp_biguint(&x.value, &bound);
+        self.assert_one(in_range.target);
+
+        for &(modulus, residue) in residues {
+            let reduced = self.reduce_mod_small_prime(&x, modulus);
+            self.connect_u32(reduced, residue);
+        }
+
+        x
+    }
+
+    fn assert_is_generator<FF: PrimeField>(
+        &mut self,
+        g: &NonNativeTarget<FF>,
+        prime_factors_of_p_minus_1: &[BigUint],
+    ) {
+        let p_minus_one = FF::order() - BigUint::one();
+        let zero = self.zero_biguint();
+        let one = self.constant_biguint(&BigUint::one());
+
+        // Zero passes every `g^((p - 1) / q) != 1` check, so it must be excluded separately.
+        let g_reduced = self.reduce_nonnative(g);
+        let is_zero = self.is_equal_biguint(&g_reduced.value, &zero);
+        self.assert_zero(is_zero.target);
+
+        for q in prime_factors_of_p_minus_1 {
+            let (exponent, rem) = p_minus_one.div_rem(q);
+            assert!(rem.is_zero(), "{} does not divide |FF| - 1", q);
+
+            let power = self.pow_const_nonnative(g, &exponent);
+            let power_reduced = self.reduce_nonnative(&power);
+            let is_one = self.is_equal_biguint(&power_reduced.value, &one);
+            self.assert_zero(is_one.target);
+        }
+    }
+}
+
+/// Decodes a nonnative value registered with `register_nonnative_public_input` from the start of
+/// `public_inputs`. The limbs use the same layout as `NonNativeTarget::elements`; any public inputs
+/// after them belong to other values and are not read. Panics if the limbs do not encode a reduced
+/// value.
+pub fn read_nonnative_public_input<F: RichField, FF: PrimeField>(public_inputs: &[F]) -> FF {
+    let num_limbs = num_nonnative_limbs::<FF>();
+    assert!(
+        public_inputs.len() >= num_limbs,
+        "expected at least {} public inputs, got {}",
+        num_limbs,
+        public_inputs.len()
+    );
+    let limbs = &public_inputs[..num_limbs];
+    let value = NonNativeTarget::<FF>::from_elements(limbs);
+    assert!(
+        NonNativeTarget::<FF>::elements::<F>(value)[..] == *limbs,
+        "public inputs do not encode a reduced nonnative value"
+    );
+    value
+}
+
+/// Returns the number of gates each primitive nonnative operation adds to a circuit with the given
+/// `config`, keyed by operation name (`add`, `sub`, `mul`, `inv` and `reduce`). Each operation is
+/// applied once to fresh virtual targets in a throwaway circuit and measured as the change in
+/// `num_gates`. Gates shared between operations, such as partially filled u32 arithmetic gates,
+/// are attributed to whichever operation allocated them, so the counts are approximate.
+pub fn nonnative_op_gate_counts<F: RichField + Extendable<D>, const D: usize, FF: PrimeField>(
+    config: &CircuitConfig,
+) -> BTreeMap<String, usize> {
+    #[allow(clippy::type_complexity)]
+    let ops: [(
+        &str,
+        fn(&mut BaseCircuitBuilder<F, D>, &NonNativeTarget<FF>, &NonNativeTarget<FF>),
+    ); 5] = [
+        ("add", |builder, a, b| {
+            builder.add_nonnative(a, b);
+        }),
+        ("sub", |builder, a, b| {
+            builder.sub_nonnative(a, b);
+        }),
+        ("mul", |builder, a, b| {
+            builder.mul_nonnative(a, b);
+        }),
+        ("inv", |builder, a, _| {
+            builder.inv_nonnative(a);
+        }),
+        ("reduce", |builder, a, _| {
+            builder.reduce_nonnative(a);
+        }),
+    ];
+
+    let mut builder = BaseCircuitBuilder::<F, D>::new(config.clone());
+    ops.iter()
+        .map(|(name, op)| {
+            let a = builder.add_virtual_nonnative_target::<FF>();
+            let b = builder.add_virtual_nonnative_target::<FF>();
+            let before = builder.num_gates();
+            op(&mut builder, &a, &b);
+            (name.to_string(), builder.num_gates() - before)
+        })
+        .collect()
 }
 
 #[derive(Debug, Default)]
@@ -566,7 +1549,7 @@ impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField>
     NonNativeAdditionGenerator<F, D, FF>
 {
     pub fn id() -> String {
-        "NonNativeAdditionGenerator".to_string()
+        nonnative_generator_id::<FF>("NonNativeAdditionGenerator")
     }
 }
 
@@ -578,9 +1561,9 @@ impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField> SimpleGenerat
     }
 
     fn serialize(&self, dst: &mut Vec<u8>, _common_data: &CommonCircuitData<F, D>) -> IoResult<()> {
-        dst.write_target_nonnative(self.a.clone())?;
-        dst.write_target_nonnative(self.b.clone())?;
-        dst.write_target_nonnative(self.sum.clone())?;
+        dst.write_target_nonnative(&self.a)?;
+        dst.write_target_nonnative(&self.b)?;
+        dst.write_target_nonnative(&self.sum)?;
         dst.write_target_bool(self.overflow)
     }
 
@@ -610,13 +1593,13 @@ impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField> SimpleGenerat
     }
 
     fn run_once(&self, witness: &PartitionWitness<F>, out_buffer: &mut GeneratedValues<F>) {
-        let a = FF::from_noncanonical_biguint(witness.get_biguint_target(self.a.value.clone()));
-        let b = FF::from_noncanonical_biguint(witness.get_biguint_target(self.b.value.clone()));
-        let a_biguint = a.to_canonical_biguint();
-        let b_biguint = b.to_canonical_biguint();
+        // The circuit constrains the sum of the limbs as given, and `add_nonnative_no_reduce` may
+        // pass unreduced inputs, so use the raw values rather than reducing them into the field.
+        let a_biguint = witness.get_biguint_target(self.a.value.clone());
+        let b_biguint = witness.get_biguint_target(self.b.value.clone());
         let sum_biguint = a_biguint + b_biguint;
         let modulus = FF::order();
-        let (overflow, sum_reduced) = if sum_biguint > modulus {
+        let (overflow, sum_reduced) = if sum_biguint >= modulus {
             (true, sum_biguint - modulus)
         } else {
             (false, sum_biguint)
@@ -627,108 +1610,484 @@ impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField> SimpleGenerat
     }
 }
 
+/// Constrains `a * b = prod + p * overflow` with `prod` and `overflow` witnessed by
+/// `NonNativeMultiplicationGenerator`. Returns `(prod, a * b, overflow)`.
+fn mul_nonnative_parts<F: RichField + Extendable<D>, const D: usize, FF: PrimeField>(
+    builder: &mut BaseCircuitBuilder<F, D>,
+    a: &NonNativeTarget<FF>,
+    b: &NonNativeTarget<FF>,
+) -> (NonNativeTarget<FF>, BigUintTarget, BigUintTarget) {
+    let prod = builder.add_virtual_nonnative_target::<FF>();
+    let modulus = builder.nonnative_modulus::<FF>();
+    let overflow = builder.add_virtual_biguint_target(mul_overflow_limbs::<FF>(
+        a.value.num_limbs(),
+        b.value.num_limbs(),
+    ));
+
+    builder.add_simple_generator(NonNativeMultiplicationGenerator::<F, D, FF> {
+        a: a.clone(),
+        b: b.clone(),
+        prod: prod.clone(),
+        overflow: overflow.clone(),
+        _phantom: PhantomData,
+    });
+
+    range_check_u32_circuit(builder, prod.value.limbs.clone());
+    range_check_u32_circuit(builder, overflow.limbs.clone());
+
+    let prod_expected = builder.mul_biguint(&a.value, &b.value);
+
+    let mod_times_overflow = builder.mul_biguint(&modulus, &overflow);
+    let prod_actual = builder.add_biguint(&prod.value, &mod_times_overflow);
+    builder.connect_biguint(&prod_expected, &prod_actual);
+
+    (prod, prod_expected, overflow)
+}
+
+/// Returns the number of limbs needed for the quotient `a * b / |FF|` of reduced operands with the
+/// given limb counts. Each operand is below both `|FF|` and `2^(32 * limbs)`, and
+/// `|FF| >= 2^(FF::BITS - 1)`, so the quotient is below `|FF|` and below
+/// `2^(32 * (a_limbs + b_limbs) - FF::BITS + 1)`. Short operands may need no quotient at all, but
+/// at least one limb is always allocated.
+fn mul_overflow_limbs<FF: PrimeField>(a_limbs: usize, b_limbs: usize) -> usize {
+    let num_limbs = num_nonnative_limbs::<FF>();
+    let quotient_bits = (32 * (a_limbs + b_limbs)).saturating_sub(FF::BITS - 1);
+    ceil_div_usize(quotient_bits, 32).clamp(1, num_limbs)
+}
+
+/// Returns the low `num_limbs` limbs of `x`, asserting that any limbs above them are zero.
+fn truncate_biguint<F: RichField + Extendable<D>, const D: usize>(
+    builder: &mut BaseCircuitBuilder<F, D>,
+    x: &BigUintTarget,
+    num_limbs: usize,
+) -> BigUintTarget {
+    if x.num_limbs() <= num_limbs {
+        return x.clone();
+    }
+    for &limb in &x.limbs[num_limbs..] {
+        builder.assert_zero_u32(limb);
+    }
+    BigUintTarget {
+        limbs: x.limbs[..num_limbs].to_vec(),
+    }
+}
+
+/// Returns `multiple * x` for a reduced `x`. Since `multiple * x < multiple * |FF|`, the result is
+/// witnessed together with `multiple - 1` bits, each of which subtracts the modulus once.
+fn small_multiple_nonnative<F: RichField + Extendable<D>, const D: usize, FF: PrimeField>(
+    builder: &mut BaseCircuitBuilder<F, D>,
+    x: &NonNativeTarget<FF>,
+    multiple: usize,
+) -> NonNativeTarget<FF> {
+    let result = builder.add_virtual_nonnative_target::<FF>();
+    let subtractions = (1..multiple)
+        .map(|_| builder.add_virtual_bool_target_safe())
+        .collect::<Vec<_>>();
+
+    builder.add_simple_generator(NonNativeSmallMultipleGenerator::<F, D, FF> {
+        x: x.clone(),
+        result: result.clone(),
+        subtractions: subtractions.clone(),
+        _phantom: PhantomData,
+    });
+
+    range_check_u32_circuit(builder, result.value.limbs.clone());
+
+    let expected = (1..multiple).fold(x.value.clone(), |acc, _| {
+        builder.add_biguint(&acc, &x.value)
+    });
+
+    let modulus = builder.nonnative_modulus::<FF>();
+    let actual = subtractions.iter().fold(result.value.clone(), |acc, &b| {
+        let mod_times_b = builder.mul_biguint_by_bool(&modulus, b);
+        builder.add_biguint(&acc, &mod_times_b)
+    });
+    builder.connect_biguint(&expected, &actual);
+
+    // Range-check result.
+    let max = builder.constant_biguint(&(FF::order() - BigUint::one()));
+    let cmp = builder.cmp_biguint(&result.value, &max);
+    builder.assert_one(cmp.target);
+
+    result
+}
+
 #[derive(Debug, Default)]
-pub struct NonNativeMultipleAddsGenerator<
+pub struct NonNativeSmallMultipleGenerator<
     F: RichField + Extendable<D>,
     const D: usize,
     FF: PrimeField,
 > {
-    summands: Vec<NonNativeTarget<FF>>,
-    sum: NonNativeTarget<FF>,
-    overflow: U32Target,
+    x: NonNativeTarget<FF>,
+    result: NonNativeTarget<FF>,
+    subtractions: Vec<BoolTarget>,
     _phantom: PhantomData<F>,
 }
 
 impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField>
-    NonNativeMultipleAddsGenerator<F, D, FF>
+    NonNativeSmallMultipleGenerator<F, D, FF>
 {
-    fn id() -> String {
-        "NonNativeMultipleAddsGenerator".to_string()
+    pub fn id() -> String {
+        nonnative_generator_id::<FF>("NonNativeSmallMultipleGenerator")
     }
 }
 
 impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField> SimpleGenerator<F, D>
-    for NonNativeMultipleAddsGenerator<F, D, FF>
+    for NonNativeSmallMultipleGenerator<F, D, FF>
 {
     fn id(&self) -> String {
         Self::id()
     }
 
     fn serialize(&self, dst: &mut Vec<u8>, _common_data: &CommonCircuitData<F, D>) -> IoResult<()> {
-        dst.write_usize(self.summands.len())?;
-        for summand in self.summands.iter() {
-            dst.write_target_nonnative((*summand).clone())?;
-        }
-        dst.write_target_nonnative(self.sum.clone())?;
-        dst.write_target_u32(self.overflow)
+        dst.write_target_nonnative(&self.x)?;
+        dst.write_target_nonnative(&self.result)?;
+        dst.write_target_bool_vec(&self.subtractions)
     }
 
     fn deserialize(src: &mut Buffer, _common_data: &CommonCircuitData<F, D>) -> IoResult<Self> {
-        let summands_len = src.read_usize()?;
-        let summands = (0..summands_len)
-            .map(|_| src.read_target_nonnative())
-            .collect::<Result<Vec<_>, _>>()?;
-        let sum = src.read_target_nonnative()?;
-        let overflow = src.read_target_u32()?;
+        let x = src.read_target_nonnative()?;
+        let result = src.read_target_nonnative()?;
+        let subtractions = src.read_target_bool_vec()?;
         Ok(Self {
-            summands,
-            sum,
-            overflow,
+            x,
+            result,
+            subtractions,
             _phantom: PhantomData,
         })
     }
 
     fn dependencies(&self) -> Vec<Target> {
-        self.summands
-            .iter()
-            .flat_map(|summand| summand.value.limbs.iter().map(|limb| limb.0))
-            .collect()
+        self.x.value.limbs.iter().map(|&l| l.0).collect()
     }
 
     fn run_once(&self, witness: &PartitionWitness<F>, out_buffer: &mut GeneratedValues<F>) {
-        let summands: Vec<_> = self
-            .summands
-            .iter()
-            .map(|summand| {
-                FF::from_noncanonical_biguint(witness.get_biguint_target(summand.value.clone()))
-            })
-            .collect();
-        let summand_biguints: Vec<_> = summands
-            .iter()
-            .map(|summand| summand.to_canonical_biguint())
-            .collect();
-
-        let sum_biguint = summand_biguints
-            .iter()
-            .fold(BigUint::zero(), |a, b| a + b.clone());
-
+        let x = witness.get_biguint_target(self.x.value.clone());
         let modulus = FF::order();
-        let (overflow_biguint, sum_reduced) = sum_biguint.div_rem(&modulus);
-        let overflow = overflow_biguint.to_u64_digits()[0] as u32;
+        let product = x * (self.subtractions.len() + 1);
+        let (quotient, result) = product.div_rem(&modulus);
+        let quotient = quotient.to_usize().unwrap();
 
-        out_buffer.set_biguint_target(&self.sum.value, &sum_reduced);
-        out_buffer.set_u32_target(self.overflow, overflow);
+        out_buffer.set_biguint_target(&self.result.value, &result);
+        for (i, &b) in self.subtractions.iter().enumerate() {
+            out_buffer.set_bool_target(b, i < quotient);
+        }
     }
 }
 
 #[derive(Debug, Default)]
-pub struct NonNativeSubtractionGenerator<
-    F: RichField + Extendable<D>,
-    const D: usize,
-    FF: PrimeField,
-> {
-    a: NonNativeTarget<FF>,
-    b: NonNativeTarget<FF>,
-    diff: NonNativeTarget<FF>,
-    overflow: BoolTarget,
+pub struct NonNativeCanonicalGenerator<F: RichField + Extendable<D>, const D: usize, FF: PrimeField>
+{
+    x: NonNativeTarget<FF>,
+    result: NonNativeTarget<FF>,
+    quotient_bits: Vec<BoolTarget>,
     _phantom: PhantomData<F>,
 }
 
 impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField>
-    NonNativeSubtractionGenerator<F, D, FF>
+    NonNativeCanonicalGenerator<F, D, FF>
 {
     pub fn id() -> String {
-        "NonNativeSubtractionGenerator".to_string()
+        nonnative_generator_id::<FF>("NonNativeCanonicalGenerator")
+    }
+}
+
+impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField> SimpleGenerator<F, D>
+    for NonNativeCanonicalGenerator<F, D, FF>
+{
+    fn id(&self) -> String {
+        Self::id()
+    }
+
+    fn serialize(&self, dst: &mut Vec<u8>, _common_data: &CommonCircuitData<F, D>) -> IoResult<()> {
+        dst.write_target_nonnative(&self.x)?;
+        dst.write_target_nonnative(&self.result)?;
+        dst.write_target_bool_vec(&self.quotient_bits)
+    }
+
+    fn deserialize(src: &mut Buffer, _common_data: &CommonCircuitData<F, D>) -> IoResult<Self> {
+        let x = src.read_target_nonnative()?;
+        let result = src.read_target_nonnative()?;
+        let quotient_bits = src.read_target_bool_vec()?;
+        Ok(Self {
+            x,
+            result,
+            quotient_bits,
+            _phantom: PhantomData,
+        })
+    }
+
+    fn dependencies(&self) -> Vec<Target> {
+        self.x.value.limbs.iter().map(|&l| l.0).collect()
+    }
+
+    fn run_once(&self, witness: &PartitionWitness<F>, out_buffer: &mut GeneratedValues<F>) {
+        let x = witness.get_biguint_target(self.x.value.clone());
+        let modulus = FF::order();
+        let (quotient, result) = x.div_rem(&modulus);
+        debug_assert!(
+            quotient.bits() as usize <= self.quotient_bits.len(),
+            "NonNativeCanonicalGenerator: input exceeds the given bound"
+        );
+
+        out_buffer.set_biguint_target(&self.result.value, &result);
+        for (i, &b) in self.quotient_bits.iter().enumerate() {
+            out_buffer.set_bool_target(b, quotient.bit(i as u64));
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct NonNativeMultipleAddsGenerator<
+    F: RichField + Extendable<D>,
+    const D: usize,
+    FF: PrimeField,
+> {
+    summands: Vec<NonNativeTarget<FF>>,
+    sum: NonNativeTarget<FF>,
+    overflow: U32Target,
+    _phantom: PhantomData<F>,
+}
+
+impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField>
+    NonNativeMultipleAddsGenerator<F, D, FF>
+{
+    fn id() -> String {
+        nonnative_generator_id::<FF>("NonNativeMultipleAddsGenerator")
+    }
+}
+
+impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField> SimpleGenerator<F, D>
+    for NonNativeMultipleAddsGenerator<F, D, FF>
+{
+    fn id(&self) -> String {
+        Self::id()
+    }
+
+    fn serialize(&self, dst: &mut Vec<u8>, _common_data: &CommonCircuitData<F, D>) -> IoResult<()> {
+        dst.write_usize(self.summands.len())?;
+        for summand in self.summands.iter() {
+            dst.write_target_nonnative(summand)?;
+        }
+        dst.write_target_nonnative(&self.sum)?;
+        dst.write_target_u32(self.overflow)
+    }
+
+    fn deserialize(src: &mut Buffer, _common_data: &CommonCircuitData<F, D>) -> IoResult<Self> {
+        let summands_len = src.read_usize()?;
+        let summands = (0..summands_len)
+            .map(|_| src.read_target_nonnative())
+            .collect::<Result<Vec<_>, _>>()?;
+        let sum = src.read_target_nonnative()?;
+        let overflow = src.read_target_u32()?;
+        Ok(Self {
+            summands,
+            sum,
+            overflow,
+            _phantom: PhantomData,
+        })
+    }
+
+    fn dependencies(&self) -> Vec<Target> {
+        self.summands
+            .iter()
+            .flat_map(|summand| summand.value.limbs.iter().map(|limb| limb.0))
+            .collect()
+    }
+
+    fn run_once(&self, witness: &PartitionWitness<F>, out_buffer: &mut GeneratedValues<F>) {
+        let summands: Vec<_> = self
+            .summands
+            .iter()
+            .map(|summand| {
+                FF::from_noncanonical_biguint(witness.get_biguint_target(summand.value.clone()))
+            })
+            .collect();
+        let summand_biguints: Vec<_> = summands
+            .iter()
+            .map(|summand| summand.to_canonical_biguint())
+            .collect();
+
+        let sum_biguint = summand_biguints
+            .iter()
+            .fold(BigUint::zero(), |a, b| a + b.clone());
+
+        let modulus = FF::order();
+        let (overflow_biguint, sum_reduced) = sum_biguint.div_rem(&modulus);
+        let overflow = overflow_biguint.to_u64_digits()[0] as u32;
+
+        out_buffer.set_biguint_target(&self.sum.value, &sum_reduced);
+        out_buffer.set_u32_target(self.overflow, overflow);
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct NonNativeSumGenerator<F: RichField + Extendable<D>, const D: usize, FF: PrimeField> {
+    summands: Vec<NonNativeTarget<FF>>,
+    sum: NonNativeTarget<FF>,
+    overflow: BigUintTarget,
+    _phantom: PhantomData<F>,
+}
+
+impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField> NonNativeSumGenerator<F, D, FF> {
+    fn id() -> String {
+        nonnative_generator_id::<FF>("NonNativeSumGenerator")
+    }
+}
+
+impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField> SimpleGenerator<F, D>
+    for NonNativeSumGenerator<F, D, FF>
+{
+    fn id(&self) -> String {
+        Self::id()
+    }
+
+    fn serialize(&self, dst: &mut Vec<u8>, _common_data: &CommonCircuitData<F, D>) -> IoResult<()> {
+        dst.write_usize(self.summands.len())?;
+        for summand in self.summands.iter() {
+            dst.write_target_nonnative(summand)?;
+        }
+        dst.write_target_nonnative(&self.sum)?;
+        dst.write_target_biguint(self.overflow.clone())
+    }
+
+    fn deserialize(src: &mut Buffer, _common_data: &CommonCircuitData<F, D>) -> IoResult<Self> {
+        let summands_len = src.read_usize()?;
+        let summands = (0..summands_len)
+            .map(|_| src.read_target_nonnative())
+            .collect::<Result<Vec<_>, _>>()?;
+        let sum = src.read_target_nonnative()?;
+        let overflow = src.read_target_biguint()?;
+        Ok(Self {
+            summands,
+            sum,
+            overflow,
+            _phantom: PhantomData,
+        })
+    }
+
+    fn dependencies(&self) -> Vec<Target> {
+        self.summands
+            .iter()
+            .flat_map(|summand| summand.value.limbs.iter().map(|limb| limb.0))
+            .collect()
+    }
+
+    fn run_once(&self, witness: &PartitionWitness<F>, out_buffer: &mut GeneratedValues<F>) {
+        // The circuit constrains the sum of the limbs as given, so use the raw values.
+        let sum_biguint = self.summands.iter().fold(BigUint::zero(), |acc, summand| {
+            acc + witness.get_biguint_target(summand.value.clone())
+        });
+
+        let modulus = FF::order();
+        let (overflow, sum_reduced) = sum_biguint.div_rem(&modulus);
+
+        out_buffer.set_biguint_target(&self.sum.value, &sum_reduced);
+        out_buffer.set_biguint_target(&self.overflow, &overflow);
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct NonNativeMultipleSubsGenerator<
+    F: RichField + Extendable<D>,
+    const D: usize,
+    FF: PrimeField,
+> {
+    base: NonNativeTarget<FF>,
+    to_subtract: Vec<NonNativeTarget<FF>>,
+    result: NonNativeTarget<FF>,
+    borrow: BigUintTarget,
+    _phantom: PhantomData<F>,
+}
+
+impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField>
+    NonNativeMultipleSubsGenerator<F, D, FF>
+{
+    fn id() -> String {
+        nonnative_generator_id::<FF>("NonNativeMultipleSubsGenerator")
+    }
+}
+
+impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField> SimpleGenerator<F, D>
+    for NonNativeMultipleSubsGenerator<F, D, FF>
+{
+    fn id(&self) -> String {
+        Self::id()
+    }
+
+    fn serialize(&self, dst: &mut Vec<u8>, _common_data: &CommonCircuitData<F, D>) -> IoResult<()> {
+        dst.write_target_nonnative(&self.base)?;
+        dst.write_usize(self.to_subtract.len())?;
+        for x in self.to_subtract.iter() {
+            dst.write_target_nonnative(x)?;
+        }
+        dst.write_target_nonnative(&self.result)?;
+        dst.write_target_biguint(self.borrow.clone())
+    }
+
+    fn deserialize(src: &mut Buffer, _common_data: &CommonCircuitData<F, D>) -> IoResult<Self> {
+        let base = src.read_target_nonnative()?;
+        let to_subtract_len = src.read_usize()?;
+        let to_subtract = (0..to_subtract_len)
+            .map(|_| src.read_target_nonnative())
+            .collect::<Result<Vec<_>, _>>()?;
+        let result = src.read_target_nonnative()?;
+        let borrow = src.read_target_biguint()?;
+        Ok(Self {
+            base,
+            to_subtract,
+            result,
+            borrow,
+            _phantom: PhantomData,
+        })
+    }
+
+    fn dependencies(&self) -> Vec<Target> {
+        self.base
+            .value
+            .limbs
+            .iter()
+            .chain(self.to_subtract.iter().flat_map(|x| x.value.limbs.iter()))
+            .map(|limb| limb.0)
+            .collect()
+    }
+
+    fn run_once(&self, witness: &PartitionWitness<F>, out_buffer: &mut GeneratedValues<F>) {
+        let base = witness.get_biguint_target(self.base.value.clone());
+        let sum = self.to_subtract.iter().fold(BigUint::zero(), |acc, x| {
+            acc + witness.get_biguint_target(x.value.clone())
+        });
+
+        // Add enough multiples of the modulus to keep the difference nonnegative, then recover the
+        // borrow from the reduced result.
+        let modulus = FF::order();
+        let offset = &modulus * self.to_subtract.len();
+        let result = (base.clone() + offset - &sum) % &modulus;
+        let borrow = (&result + sum - base) / &modulus;
+
+        out_buffer.set_biguint_target(&self.result.value, &result);
+        out_buffer.set_biguint_target(&self.borrow, &borrow);
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct NonNativeSubtractionGenerator<
+    F: RichField + Extendable<D>,
+    const D: usize,
+    FF: PrimeField,
+> {
+    a: NonNativeTarget<FF>,
+    b: NonNativeTarget<FF>,
+    diff: NonNativeTarget<FF>,
+    overflow: BoolTarget,
+    _phantom: PhantomData<F>,
+}
+
+impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField>
+    NonNativeSubtractionGenerator<F, D, FF>
+{
+    pub fn id() -> String {
+        nonnative_generator_id::<FF>("NonNativeSubtractionGenerator")
     }
 }
 
@@ -740,9 +2099,9 @@ impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField> SimpleGenerat
     }
 
     fn serialize(&self, dst: &mut Vec<u8>, _common_data: &CommonCircuitData<F, D>) -> IoResult<()> {
-        dst.write_target_nonnative(self.a.clone())?;
-        dst.write_target_nonnative(self.b.clone())?;
-        dst.write_target_nonnative(self.diff.clone())?;
+        dst.write_target_nonnative(&self.a)?;
+        dst.write_target_nonnative(&self.b)?;
+        dst.write_target_nonnative(&self.diff)?;
         dst.write_target_bool(self.overflow)
     }
 
@@ -806,7 +2165,7 @@ impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField>
     NonNativeMultiplicationGenerator<F, D, FF>
 {
     fn id() -> String {
-        "NonNativeMultiplicationGenerator".to_string()
+        nonnative_generator_id::<FF>("NonNativeMultiplicationGenerator")
     }
 }
 
@@ -818,9 +2177,9 @@ impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField> SimpleGenerat
     }
 
     fn serialize(&self, dst: &mut Vec<u8>, _common_data: &CommonCircuitData<F, D>) -> IoResult<()> {
-        dst.write_target_nonnative(self.a.clone())?;
-        dst.write_target_nonnative(self.b.clone())?;
-        dst.write_target_nonnative(self.prod.clone())?;
+        dst.write_target_nonnative(&self.a)?;
+        dst.write_target_nonnative(&self.b)?;
+        dst.write_target_nonnative(&self.prod)?;
         dst.write_target_biguint(self.overflow.clone())
     }
 
@@ -850,16 +2209,24 @@ impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField> SimpleGenerat
     }
 
     fn run_once(&self, witness: &PartitionWitness<F>, out_buffer: &mut GeneratedValues<F>) {
-        let a = FF::from_noncanonical_biguint(witness.get_biguint_target(self.a.value.clone()));
-        let b = FF::from_noncanonical_biguint(witness.get_biguint_target(self.b.value.clone()));
-        let a_biguint = a.to_canonical_biguint();
-        let b_biguint = b.to_canonical_biguint();
+        // The circuit constrains the product of the limbs as given, so use the raw values rather
+        // than reducing them into the field first.
+        let a_biguint = witness.get_biguint_target(self.a.value.clone());
+        let b_biguint = witness.get_biguint_target(self.b.value.clone());
 
         let prod_biguint = a_biguint * b_biguint;
 
         let modulus = FF::order();
         let (overflow_biguint, prod_reduced) = prod_biguint.div_rem(&modulus);
 
+        debug_assert!(
+            overflow_biguint.to_u32_digits().len() <= self.overflow.num_limbs(),
+            "NonNativeMultiplicationGenerator: quotient needs {} limbs but only {} were allocated; \
+             are the operands reduced?",
+            overflow_biguint.to_u32_digits().len(),
+            self.overflow.num_limbs(),
+        );
+
         out_buffer.set_biguint_target(&self.prod.value, &prod_reduced);
         out_buffer.set_biguint_target(&self.overflow, &overflow_biguint);
     }
@@ -877,7 +2244,7 @@ impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField>
     NonNativeInverseGenerator<F, D, FF>
 {
     fn id() -> String {
-        "NonNativeInverseGenerator".to_string()
+        nonnative_generator_id::<FF>("NonNativeInverseGenerator")
     }
 }
 
@@ -889,7 +2256,7 @@ impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField> SimpleGenerat
     }
 
     fn serialize(&self, dst: &mut Vec<u8>, _common_data: &CommonCircuitData<F, D>) -> IoResult<()> {
-        dst.write_target_nonnative(self.x.clone())?;
+        dst.write_target_nonnative(&self.x)?;
         dst.write_target_biguint(self.inv.clone())?;
         dst.write_target_biguint(self.div.clone())
     }
@@ -925,24 +2292,189 @@ impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField> SimpleGenerat
     }
 }
 
-pub trait WriteNonNativeTarget {
-    fn write_target_nonnative<FF: PrimeField>(&mut self, x: NonNativeTarget<FF>) -> IoResult<()>;
+#[derive(Debug, Default)]
+pub struct NonNativeSqrtGenerator<F: RichField + Extendable<D>, const D: usize, FF: PrimeField> {
+    x: NonNativeTarget<FF>,
+    root: NonNativeTarget<FF>,
+    exists: BoolTarget,
+    _phantom: PhantomData<F>,
 }
 
-impl WriteNonNativeTarget for Vec<u8> {
-    #[inline]
-    fn write_target_nonnative<FF: PrimeField>(&mut self, x: NonNativeTarget<FF>) -> IoResult<()> {
-        self.write_target_biguint(x.value)
+impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField>
+    NonNativeSqrtGenerator<F, D, FF>
+{
+    fn id() -> String {
+        nonnative_generator_id::<FF>("NonNativeSqrtGenerator")
     }
 }
 
-pub trait ReadNonNativeTarget {
-    fn read_target_nonnative<FF: PrimeField>(&mut self) -> IoResult<NonNativeTarget<FF>>;
-}
+impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField> SimpleGenerator<F, D>
+    for NonNativeSqrtGenerator<F, D, FF>
+{
+    fn id(&self) -> String {
+        Self::id()
+    }
 
-impl ReadNonNativeTarget for Buffer<'_> {
+    fn serialize(&self, dst: &mut Vec<u8>, _common_data: &CommonCircuitData<F, D>) -> IoResult<()> {
+        dst.write_target_nonnative(&self.x)?;
+        dst.write_target_nonnative(&self.root)?;
+        dst.write_target_bool(self.exists)
+    }
+
+    fn deserialize(src: &mut Buffer, _common_data: &CommonCircuitData<F, D>) -> IoResult<Self> {
+        let x = src.read_target_nonnative()?;
+        let root = src.read_target_nonnative()?;
+        let exists = src.read_target_bool()?;
+        Ok(Self {
+            x,
+            root,
+            exists,
+            _phantom: PhantomData,
+        })
+    }
+
+    fn dependencies(&self) -> Vec<Target> {
+        self.x.value.limbs.iter().map(|&l| l.0).collect()
+    }
+
+    fn run_once(&self, witness: &PartitionWitness<F>, out_buffer: &mut GeneratedValues<F>) {
+        let x = FF::from_noncanonical_biguint(witness.get_biguint_target(self.x.value.clone()));
+        let (root, exists) = match x.sqrt() {
+            Some(root) => (root, true),
+            None => (FF::ZERO, false),
+        };
+
+        out_buffer.set_biguint_target(&self.root.value, &root.to_canonical_biguint());
+        out_buffer.set_bool_target(self.exists, exists);
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct NonNativeCrtGenerator<F: RichField + Extendable<D>, const D: usize, FF: PrimeField> {
+    residues: Vec<(u32, U32Target)>,
+    x: NonNativeTarget<FF>,
+    _phantom: PhantomData<F>,
+}
+
+impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField> NonNativeCrtGenerator<F, D, FF> {
+    fn id() -> String {
+        nonnative_generator_id::<FF>("NonNativeCrtGenerator")
+    }
+}
+
+impl<F: RichField + Extendable<D>, const D: usize, FF: PrimeField> SimpleGenerator<F, D>
+    for NonNativeCrtGenerator<F, D, FF>
+{
+    fn id(&self) -> String {
+        Self::id()
+    }
+
+    fn serialize(&self, dst: &mut Vec<u8>, _common_data: &CommonCircuitData<F, D>) -> IoResult<()> {
+        dst.write_usize(self.residues.len())?;
+        for &(modulus, residue) in self.residues.iter() {
+            dst.write_usize(modulus as usize)?;
+            dst.write_target_u32(residue)?;
+        }
+        dst.write_target_nonnative(&self.x)
+    }
+
+    fn deserialize(src: &mut Buffer, _common_data: &CommonCircuitData<F, D>) -> IoResult<Self> {
+        let residues_len = src.read_usize()?;
+        let mut residues = Vec::with_capacity(residues_len);
+        for _ in 0..residues_len {
+            let modulus = src.read_usize()? as u32;
+            let residue = src.read_target_u32()?;
+            residues.push((modulus, residue));
+        }
+        let x = src.read_target_nonnative()?;
+        Ok(Self {
+            residues,
+            x,
+            _phantom: PhantomData,
+        })
+    }
+
+    fn dependencies(&self) -> Vec<Target> {
+        self.residues.iter().map(|&(_, r)| r.0).collect()
+    }
+
+    fn run_once(&self, witness: &PartitionWitness<F>, out_buffer: &mut GeneratedValues<F>) {
+        let product = self
+            .residues
+            .iter()
+            .map(|&(m, _)| BigUint::from(m))
+            .product::<BigUint>();
+
+        // x = sum_i r_i * (M / m_i) * ((M / m_i)^{-1} mod m_i) mod M
+        let mut x = BigUint::zero();
+        for &(modulus, residue) in self.residues.iter() {
+            let residue = witness.get_target(residue.0).to_canonical_u64();
+            let cofactor = &product / modulus;
+            let cofactor_mod = (&cofactor % modulus).to_u64().unwrap();
+            let inverse = inverse_mod_u64(cofactor_mod, modulus as u64);
+            x += cofactor * residue * inverse;
+        }
+        x %= &product;
+
+        out_buffer.set_biguint_target(&self.x.value, &x);
+    }
+}
+
+/// Returns the inverse of `a` modulo `m` using the extended Euclidean algorithm. Assumes `a` and
+/// `m` are coprime.
+fn inverse_mod_u64(a: u64, m: u64) -> u64 {
+    let (mut old_r, mut r) = (a as i128, m as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+    old_s.rem_euclid(m as i128) as u64
+}
+
+pub trait WitnessNonNative<F: PrimeField64>: Witness<F> {
+    fn get_nonnative_target<FF: PrimeField>(&self, target: NonNativeTarget<FF>) -> FF;
+    fn set_nonnative_target<FF: PrimeField>(&mut self, target: &NonNativeTarget<FF>, value: FF);
+}
+
+impl<T: Witness<F>, F: PrimeField64> WitnessNonNative<F> for T {
+    fn get_nonnative_target<FF: PrimeField>(&self, target: NonNativeTarget<FF>) -> FF {
+        FF::from_noncanonical_biguint(self.get_biguint_target(target.value))
+    }
+
+    fn set_nonnative_target<FF: PrimeField>(&mut self, target: &NonNativeTarget<FF>, value: FF) {
+        // `set_biguint_target` pads the canonical limbs with zeros up to the target's limb count.
+        self.set_biguint_target(&target.value, &value.to_canonical_biguint());
+    }
+}
+
+/// Version tag prefixed to every serialized `NonNativeTarget`, to be bumped whenever the limb
+/// format changes.
+const NONNATIVE_TARGET_SERIALIZATION_VERSION: u8 = 1;
+
+pub trait WriteNonNativeTarget {
+    fn write_target_nonnative<FF: PrimeField>(&mut self, x: &NonNativeTarget<FF>) -> IoResult<()>;
+}
+
+impl WriteNonNativeTarget for Vec<u8> {
+    #[inline]
+    fn write_target_nonnative<FF: PrimeField>(&mut self, x: &NonNativeTarget<FF>) -> IoResult<()> {
+        self.write_u8(NONNATIVE_TARGET_SERIALIZATION_VERSION)?;
+        self.write_target_biguint(x.value.clone())
+    }
+}
+
+pub trait ReadNonNativeTarget {
+    fn read_target_nonnative<FF: PrimeField>(&mut self) -> IoResult<NonNativeTarget<FF>>;
+}
+
+impl ReadNonNativeTarget for Buffer<'_> {
     #[inline]
     fn read_target_nonnative<FF: PrimeField>(&mut self) -> IoResult<NonNativeTarget<FF>> {
+        if self.read_u8()? != NONNATIVE_TARGET_SERIALIZATION_VERSION {
+            return Err(IoError);
+        }
         let value = self.read_target_biguint()?;
         Ok(NonNativeTarget {
             value,
@@ -954,14 +2486,44 @@ impl ReadNonNativeTarget for Buffer<'_> {
 #[cfg(test)]
 mod tests {
 
+    use core::marker::PhantomData;
+
+    use ethers::types::{H256, U256};
+    use num::bigint::RandBigInt;
+    use num::{BigUint, One, ToPrimitive, Zero};
+    use plonky2::field::extension::Extendable;
+    use plonky2::field::goldilocks_field::GoldilocksField;
     use plonky2::field::secp256k1_base::Secp256K1Base;
+    use plonky2::field::secp256k1_scalar::Secp256K1Scalar;
     use plonky2::field::types::{Field, PrimeField, Sample};
-    use plonky2::iop::witness::PartialWitness;
+    use plonky2::hash::hash_types::RichField;
+    use plonky2::iop::generator::SimpleGenerator;
+    use plonky2::iop::target::Target;
+    use plonky2::iop::witness::{PartialWitness, WitnessWrite};
     use plonky2::plonk::circuit_builder::CircuitBuilder as BaseCircuitBuilder;
-    use plonky2::plonk::circuit_data::CircuitConfig;
+    use plonky2::plonk::circuit_data::{CircuitConfig, CommonCircuitData};
     use plonky2::plonk::config::{GenericConfig, PoseidonGoldilocksConfig};
-
-    use crate::frontend::num::nonnative::nonnative::CircuitBuilderNonNative;
+    use plonky2::util::serialization::Buffer;
+    use rand::{thread_rng, Rng};
+
+    use crate::backend::circuit::{
+        CircuitBuild, DefaultParameters, GateRegistry, HintRegistry, PlonkParameters,
+    };
+    use crate::frontend::ecc::ed25519::field::ed25519_base::Ed25519Base;
+    use crate::frontend::ecc::ed25519::field::ed25519_scalar::Ed25519Scalar;
+    use crate::frontend::num::biguint::{BigUintTarget, CircuitBuilderBiguint, WitnessBigUint};
+    use crate::frontend::num::nonnative::nonnative::{
+        nonnative_op_gate_counts, read_nonnative_public_input, CircuitBuilderNonNative,
+        NonNativeAdditionGenerator, NonNativeMultipleAddsGenerator,
+        NonNativeMultiplicationGenerator, NonNativeSubtractionGenerator, NonNativeTarget,
+        ReadNonNativeTarget, WitnessNonNative, WriteNonNativeTarget,
+        NONNATIVE_TARGET_SERIALIZATION_VERSION,
+    };
+    use crate::frontend::num::u32::gadgets::arithmetic_u32::CircuitBuilderU32;
+    use crate::frontend::num::u32::witness::WitnessU32;
+    use crate::prelude::{
+        Bytes32Variable, CircuitBuilder, CircuitVariable, U256Variable, Variable,
+    };
 
     #[test]
     fn test_nonnative_add() {
@@ -1105,6 +2667,31 @@ mod tests {
         let neg_x_expected = builder.constant_nonnative(neg_x_ff);
         builder.connect_nonnative(&neg_x, &neg_x_expected);
 
+        let zero = builder.zero_nonnative::<FF>();
+        let neg_zero = builder.neg_nonnative(&zero);
+        builder.connect_nonnative(&neg_zero, &zero);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_nonnative_neg_unreduced() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let x = builder.constant_biguint(&(FF::order() + BigUint::one()));
+        let x = builder.biguint_to_nonnative::<FF>(&x);
+        builder.neg_nonnative(&x);
+
         let data = builder.build::<C>();
         let proof = data.prove(pw).unwrap();
         data.verify(proof).unwrap();
@@ -1133,4 +2720,1529 @@ mod tests {
         let proof = data.prove(pw).unwrap();
         data.verify(proof).unwrap();
     }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "are the operands reduced?")]
+    fn test_nonnative_mul_unreduced_overflow() {
+        type FF = Ed25519Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        // Both operands fit in the limbs of a virtual target, but are roughly twice the modulus.
+        let unreduced = (BigUint::one() << 256) - BigUint::one();
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let x = builder.add_virtual_nonnative_target::<FF>();
+        let y = builder.add_virtual_nonnative_target::<FF>();
+        pw.set_biguint_target(&x.value, &unreduced);
+        pw.set_biguint_target(&y.value, &unreduced);
+        builder.mul_nonnative(&x, &y);
+
+        let data = builder.build::<C>();
+        data.prove(pw).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_is_square() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        let y_ff = FF::rand();
+        // Since |FF| = 3 mod 4, -1 is a non-residue and so is -y^2.
+        let residue_ff = y_ff * y_ff;
+        let non_residue_ff = -residue_ff;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let zero = builder.add_virtual_nonnative_target::<FF>();
+        let residue = builder.add_virtual_nonnative_target::<FF>();
+        let non_residue = builder.add_virtual_nonnative_target::<FF>();
+        pw.set_biguint_target(&zero.value, &FF::ZERO.to_canonical_biguint());
+        pw.set_biguint_target(&residue.value, &residue_ff.to_canonical_biguint());
+        pw.set_biguint_target(&non_residue.value, &non_residue_ff.to_canonical_biguint());
+
+        let zero_is_square = builder.is_square_nonnative(&zero);
+        let residue_is_square = builder.is_square_nonnative(&residue);
+        let non_residue_is_square = builder.is_square_nonnative(&non_residue);
+        builder.assert_one(zero_is_square.target);
+        builder.assert_one(residue_is_square.target);
+        builder.assert_zero(non_residue_is_square.target);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_is_quadratic_residue() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        // 4 is trivially a residue, and since |FF| = 3 mod 4, -1 is a non-residue.
+        let residue_ff = FF::from_canonical_u64(4);
+        let non_residue_ff = FF::NEG_ONE;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let zero = builder.add_virtual_nonnative_target::<FF>();
+        let residue = builder.add_virtual_nonnative_target::<FF>();
+        let non_residue = builder.add_virtual_nonnative_target::<FF>();
+        pw.set_biguint_target(&zero.value, &FF::ZERO.to_canonical_biguint());
+        pw.set_biguint_target(&residue.value, &residue_ff.to_canonical_biguint());
+        pw.set_biguint_target(&non_residue.value, &non_residue_ff.to_canonical_biguint());
+
+        let zero_is_residue = builder.is_quadratic_residue_nonnative(&zero);
+        let residue_is_residue = builder.is_quadratic_residue_nonnative(&residue);
+        let non_residue_is_residue = builder.is_quadratic_residue_nonnative(&non_residue);
+        builder.assert_zero(zero_is_residue.target);
+        builder.assert_one(residue_is_residue.target);
+        builder.assert_zero(non_residue_is_residue.target);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_sqrt() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        let x_ff = FF::rand();
+        let y_ff = x_ff * x_ff;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let y = builder.constant_nonnative(y_ff);
+        let (root, exists) = builder.sqrt_nonnative(&y);
+        builder.assert_one(exists.target);
+
+        let root_squared = builder.square_nonnative(&root);
+        builder.connect_nonnative(&root_squared, &y);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_reduce_balanced() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+        let modulus = FF::order();
+        let half = (&modulus - BigUint::one()) >> 1;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        // Each case is an input, followed by its expected magnitude and sign.
+        let cases = [
+            (half.clone(), half.clone(), false),
+            (&half + BigUint::one(), half.clone(), true),
+            (&half - BigUint::one(), &half - BigUint::one(), false),
+            (&modulus + &half, half.clone(), false),
+            (&modulus + &half + BigUint::one(), half.clone(), true),
+            (&modulus - BigUint::one(), BigUint::one(), true),
+        ];
+        for (input, expected_magnitude, expected_is_negative) in cases {
+            let x = builder.constant_biguint(&input);
+            let (magnitude, is_negative) = builder.reduce_balanced::<FF>(&x);
+
+            let magnitude_expected = builder.constant_biguint(&expected_magnitude);
+            builder.connect_biguint(&magnitude.value, &magnitude_expected);
+            let is_negative_expected = builder.constant_bool(expected_is_negative);
+            builder.connect(is_negative.target, is_negative_expected.target);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_crt_reconstruct() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let moduli = [65521u32, 65519, 65497, 65479];
+        let value = BigUint::from(0x1234_5678_9abc_def0u64);
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let residues = moduli
+            .iter()
+            .map(|&m| {
+                let residue = builder.add_virtual_u32_target();
+                pw.set_u32_target(residue, (&value % m).to_u32().unwrap());
+                (m, residue)
+            })
+            .collect::<Vec<_>>();
+
+        let x = builder.crt_reconstruct::<FF>(&residues);
+        let expected_x = builder.constant_biguint(&value);
+        builder.connect_biguint(&x.value, &expected_x);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_ops_add_sub() {
+        type FF = Secp256K1Base;
+
+        let x_ff = FF::rand();
+        let y_ff = FF::rand();
+
+        let mut builder = CircuitBuilder::<DefaultParameters, 2>::new();
+
+        let x = builder.api.constant_nonnative(x_ff);
+        let y = builder.api.constant_nonnative(y_ff);
+        let sum = builder.add(x.clone(), y.clone());
+        let diff = builder.sub(x, y.clone());
+        let neg = builder.neg(y);
+
+        let sum_expected = builder.api.constant_nonnative(x_ff + y_ff);
+        let diff_expected = builder.api.constant_nonnative(x_ff - y_ff);
+        let neg_expected = builder.api.constant_nonnative(-y_ff);
+        builder.api.connect_nonnative(&sum, &sum_expected);
+        builder.api.connect_nonnative(&diff, &diff_expected);
+        builder.api.connect_nonnative(&neg, &neg_expected);
+
+        let circuit = builder.build();
+        let pw = PartialWitness::new();
+        let proof = circuit.data.prove(pw).unwrap();
+        circuit.data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_ops_mul_add() {
+        type FF = Secp256K1Base;
+
+        let x_ff = FF::rand();
+        let y_ff = FF::rand();
+        let z_ff = FF::rand();
+
+        let mut builder = CircuitBuilder::<DefaultParameters, 2>::new();
+
+        let x = builder.api.constant_nonnative(x_ff);
+        let y = builder.api.constant_nonnative(y_ff);
+        let z = builder.api.constant_nonnative(z_ff);
+        let product = builder.mul(x, y);
+        let result = builder.add(product, z);
+
+        let result_expected = builder.api.constant_nonnative(x_ff * y_ff + z_ff);
+        builder.api.connect_nonnative(&result, &result_expected);
+
+        let circuit = builder.build();
+        let pw = PartialWitness::new();
+        let proof = circuit.data.prove(pw).unwrap();
+        circuit.data.verify(proof).unwrap();
+    }
+
+    fn secp256k1_base_p_minus_1_factors() -> Vec<BigUint> {
+        vec![
+            BigUint::from(2u32),
+            BigUint::from(3u32),
+            BigUint::from(7u32),
+            BigUint::from(13441u32),
+            BigUint::parse_bytes(
+                b"205115282021455665897114700593932402728804164701536103180137503955397371",
+                10,
+            )
+            .unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_nonnative_assert_is_generator() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let g = builder.add_virtual_nonnative_target::<FF>();
+        pw.set_biguint_target(&g.value, &BigUint::from(3u32));
+        builder.assert_is_generator(&g, &secp256k1_base_p_minus_1_factors());
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_nonnative_assert_is_generator_non_generator() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        // 4 is a square, so its order divides (p - 1) / 2.
+        let g = builder.add_virtual_nonnative_target::<FF>();
+        pw.set_biguint_target(&g.value, &BigUint::from(4u32));
+        builder.assert_is_generator(&g, &secp256k1_base_p_minus_1_factors());
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_witness_nonnative() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let x_ff = FF::rand();
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let x = builder.add_virtual_nonnative_target::<FF>();
+        pw.set_nonnative_target(&x, x_ff);
+        assert_eq!(pw.get_nonnative_target(x.clone()), x_ff);
+
+        let x_expected = builder.constant_nonnative(x_ff);
+        builder.connect_nonnative(&x, &x_expected);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_mul_with_product() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let x_ff = FF::rand();
+        let y_ff = FF::rand();
+        let full_product = x_ff.to_canonical_biguint() * y_ff.to_canonical_biguint();
+
+        let config = CircuitConfig::standard_ecc_config();
+        let pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let x = builder.constant_nonnative(x_ff);
+        let y = builder.constant_nonnative(y_ff);
+        let (product, full) = builder.mul_nonnative_with_product(&x, &y);
+
+        let product_expected = builder.constant_nonnative(x_ff * y_ff);
+        builder.connect_nonnative(&product, &product_expected);
+        let full_expected = builder.constant_biguint(&full_product);
+        builder.connect_biguint(&full, &full_expected);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_constant_padding() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let one = builder.constant_nonnative(FF::ONE);
+        let zero = builder.zero_nonnative::<FF>();
+        assert_eq!(one.value.num_limbs(), 8);
+        assert_eq!(zero.value.num_limbs(), 8);
+
+        let one_virtual = builder.add_virtual_nonnative_target::<FF>();
+        pw.set_nonnative_target(&one_virtual, FF::ONE);
+        builder.connect_nonnative(&one, &one_virtual);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_mul_const() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let x_ff = FF::rand();
+        // A random constant, a small one with a single limb, and one with zero low limbs.
+        let constants = [
+            FF::rand(),
+            FF::from_canonical_u32(8),
+            FF::from_noncanonical_biguint(BigUint::from(0xdead_beefu32) << 96),
+            FF::ZERO,
+            FF::ONE,
+        ];
+
+        let config = CircuitConfig::standard_ecc_config();
+        let pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let x = builder.constant_nonnative(x_ff);
+        for c in constants {
+            let product = builder.mul_const_nonnative(&x, c);
+            let product_expected = builder.constant_nonnative(x_ff * c);
+            builder.connect_nonnative(&product, &product_expected);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_reduce_barrett() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let mut rng = thread_rng();
+        let modulus = FF::order();
+        let mut cases = vec![
+            // The quotient estimate is off by one for both of these.
+            (8, modulus.clone()),
+            (8, (BigUint::one() << 256) - BigUint::one()),
+            (16, (BigUint::one() << 512) - BigUint::one()),
+            (4, BigUint::from(12345u32)),
+        ];
+        for num_limbs in [8, 9, 12, 16] {
+            cases.push((num_limbs, rng.gen_biguint(32 * num_limbs as u64)));
+        }
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        for (num_limbs, value) in cases {
+            let x = builder.add_virtual_biguint_target(num_limbs);
+            pw.set_biguint_target(&x, &value);
+
+            let reduced = builder.reduce::<FF>(&x);
+            let reduced_expected = builder.constant_biguint(&(&value % &modulus));
+            builder.connect_biguint(&reduced.value, &reduced_expected);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_reduce_wide() {
+        type FF = Secp256K1Scalar;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let mut rng = thread_rng();
+        let digest = (0..64).map(|_| rng.gen::<u8>()).collect::<Vec<_>>();
+        let digest_value = BigUint::from_bytes_be(&digest);
+        let expected = FF::from_noncanonical_biguint(digest_value.clone());
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let x = builder.add_virtual_biguint_target(16);
+        pw.set_biguint_target(&x, &digest_value);
+
+        let reduced = builder.reduce_wide::<FF>(&x);
+        let reduced_expected = builder.constant_nonnative(expected);
+        builder.connect_nonnative(&reduced, &reduced_expected);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_random_access() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let values = [FF::rand(), FF::rand(), FF::rand()];
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let v = values
+            .iter()
+            .map(|&x| builder.constant_nonnative(x))
+            .collect::<Vec<_>>();
+        for (i, &value) in values.iter().enumerate() {
+            let index = builder.add_virtual_target();
+            pw.set_target(index, F::from_canonical_usize(i));
+
+            let selected = builder.random_access_nonnative(index, v.clone());
+            let selected_expected = builder.constant_nonnative(value);
+            builder.connect_nonnative(&selected, &selected_expected);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_nonnative_random_access_out_of_range() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let v = (0..3)
+            .map(|_| builder.constant_nonnative(FF::rand()))
+            .collect::<Vec<_>>();
+        // Index 3 lands on the zero padding entry, which must not be selectable.
+        let index = builder.add_virtual_target();
+        pw.set_target(index, F::from_canonical_usize(3));
+        builder.random_access_nonnative(index, v);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_select() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let x_ff = FF::rand();
+        let y_ff = FF::rand();
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let x = builder.constant_nonnative(x_ff);
+        let y = builder.constant_nonnative(y_ff);
+
+        // A constant selector picks a branch without adding any gates.
+        let num_gates = builder.num_gates();
+        let t = builder._true();
+        let f = builder._false();
+        let selected_x = builder.select_nonnative(t, &x, &y);
+        let selected_y = builder.select_nonnative(f, &x, &y);
+        assert_eq!(builder.num_gates(), num_gates);
+        builder.connect_nonnative(&selected_x, &x);
+        builder.connect_nonnative(&selected_y, &y);
+
+        for b_value in [true, false] {
+            let b = builder.add_virtual_bool_target_safe();
+            pw.set_bool_target(b, b_value);
+            let selected = builder.select_nonnative(b, &x, &y);
+            let selected_expected = builder.constant_nonnative(if b_value { x_ff } else { y_ff });
+            builder.connect_nonnative(&selected, &selected_expected);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_u256_round_trip() {
+        type FF = Secp256K1Base;
+
+        let mut builder = CircuitBuilder::<DefaultParameters, 2>::new();
+
+        for _ in 0..4 {
+            let x_ff = FF::rand();
+            let x_u256 = U256::from_little_endian(&x_ff.to_canonical_biguint().to_bytes_le());
+
+            let x = builder.api.constant_nonnative(x_ff);
+            let u256 = builder.nonnative_to_u256(&x);
+            let u256_expected = builder.constant::<U256Variable>(x_u256);
+            builder.assert_is_equal(u256, u256_expected);
+
+            let x_round_trip = builder.u256_to_nonnative::<FF>(u256);
+            builder.api.connect_nonnative(&x_round_trip, &x);
+        }
+
+        // Values of at least `|FF|` are reduced.
+        let max = builder.constant::<U256Variable>(U256::MAX);
+        let max_reduced = builder.u256_to_nonnative::<FF>(max);
+        let max_expected = builder
+            .api
+            .constant_nonnative(FF::from_noncanonical_biguint(
+                (BigUint::one() << 256) - BigUint::one(),
+            ));
+        builder.api.connect_nonnative(&max_reduced, &max_expected);
+
+        let circuit = builder.build();
+        let pw = PartialWitness::new();
+        let proof = circuit.data.prove(pw).unwrap();
+        circuit.data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_bytes32_round_trip() {
+        type FF = Secp256K1Base;
+
+        let bytes_value: [u8; 32] = core::array::from_fn(|i| i as u8 + 1);
+
+        let mut builder = CircuitBuilder::<DefaultParameters, 2>::new();
+        let bytes = builder.constant::<Bytes32Variable>(H256::from(bytes_value));
+
+        for big_endian in [true, false] {
+            let x_value = if big_endian {
+                BigUint::from_bytes_be(&bytes_value)
+            } else {
+                BigUint::from_bytes_le(&bytes_value)
+            };
+
+            let x = builder.nonnative_from_bytes32::<FF>(bytes, big_endian);
+            let x_expected = builder
+                .api
+                .constant_nonnative(FF::from_noncanonical_biguint(x_value));
+            builder.api.connect_nonnative(&x, &x_expected);
+
+            let bytes_round_trip = builder.nonnative_to_bytes32(&x, big_endian);
+            builder.assert_is_equal(bytes_round_trip, bytes);
+        }
+
+        let circuit = builder.build();
+        let pw = PartialWitness::new();
+        let proof = circuit.data.prove(pw).unwrap();
+        circuit.data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_double_triple() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        // Values either side of a third and a half of the modulus exercise every subtraction count.
+        let values = [
+            FF::rand(),
+            FF::ZERO,
+            FF::NEG_ONE,
+            FF::from_noncanonical_biguint(FF::order() / 3u32),
+            FF::from_noncanonical_biguint(FF::order() / 3u32 + 1u32),
+            FF::from_noncanonical_biguint(FF::order() / 2u32),
+            FF::from_noncanonical_biguint(FF::order() / 2u32 + 1u32),
+        ];
+        for x_ff in values {
+            let x = builder.constant_nonnative(x_ff);
+
+            let double = builder.double_nonnative(&x);
+            let double_expected = builder.add_nonnative(&x, &x);
+            builder.connect_nonnative(&double, &double_expected);
+
+            let triple = builder.triple_nonnative(&x);
+            let triple_expected = builder.add_many_nonnative(&[x.clone(), x.clone(), x]);
+            builder.connect_nonnative(&triple, &triple_expected);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    fn assert_generator_round_trip<
+        F: RichField + Extendable<D>,
+        const D: usize,
+        G: SimpleGenerator<F, D>,
+    >(
+        generator: &G,
+        common_data: &CommonCircuitData<F, D>,
+    ) {
+        let mut bytes = Vec::new();
+        generator.serialize(&mut bytes, common_data).unwrap();
+        let deserialized = G::deserialize(&mut Buffer::new(&bytes), common_data).unwrap();
+
+        let mut bytes_round_trip = Vec::new();
+        deserialized
+            .serialize(&mut bytes_round_trip, common_data)
+            .unwrap();
+        assert_eq!(bytes, bytes_round_trip);
+    }
+
+    #[test]
+    fn test_nonnative_generator_serialization() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let a = builder.add_virtual_nonnative_target::<FF>();
+        let b = builder.add_virtual_nonnative_target::<FF>();
+        let c = builder.add_virtual_nonnative_target::<FF>();
+
+        let addition = NonNativeAdditionGenerator::<F, D, FF> {
+            a: a.clone(),
+            b: b.clone(),
+            sum: c.clone(),
+            overflow: builder.add_virtual_bool_target_safe(),
+            _phantom: PhantomData,
+        };
+        let multiple_adds = NonNativeMultipleAddsGenerator::<F, D, FF> {
+            summands: vec![a.clone(), b.clone(), c.clone()],
+            sum: c.clone(),
+            overflow: builder.add_virtual_u32_target(),
+            _phantom: PhantomData,
+        };
+        let subtraction = NonNativeSubtractionGenerator::<F, D, FF> {
+            a: a.clone(),
+            b: b.clone(),
+            diff: c.clone(),
+            overflow: builder.add_virtual_bool_target_safe(),
+            _phantom: PhantomData,
+        };
+        let multiplication = NonNativeMultiplicationGenerator::<F, D, FF> {
+            a: a.clone(),
+            b,
+            prod: c,
+            overflow: builder.add_virtual_biguint_target(8),
+            _phantom: PhantomData,
+        };
+
+        // A target with an unknown version tag is rejected.
+        let mut bytes = Vec::new();
+        bytes.write_target_nonnative(&a).unwrap();
+        assert!(Buffer::new(&bytes).read_target_nonnative::<FF>().is_ok());
+        bytes[0] = NONNATIVE_TARGET_SERIALIZATION_VERSION + 1;
+        assert!(Buffer::new(&bytes).read_target_nonnative::<FF>().is_err());
+
+        let data = builder.build::<C>();
+        assert_generator_round_trip(&addition, &data.common);
+        assert_generator_round_trip(&multiple_adds, &data.common);
+        assert_generator_round_trip(&subtraction, &data.common);
+        assert_generator_round_trip(&multiplication, &data.common);
+    }
+
+    #[test]
+    fn test_nonnative_modulus_is_shared() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let limb_targets = |x: &BigUintTarget| x.limbs.iter().map(|l| l.0).collect::<Vec<Target>>();
+
+        // The modulus is the same constant `|FF|` that `constant_biguint` registers.
+        let order_constant = builder.constant_biguint(&Secp256K1Base::order());
+
+        let mut sum = builder.constant_nonnative(Secp256K1Base::rand());
+        let mut moduli = Vec::new();
+        for _ in 0..16 {
+            let x = builder.constant_nonnative(Secp256K1Base::rand());
+            sum = builder.add_nonnative(&sum, &x);
+            moduli.push(limb_targets(&builder.nonnative_modulus::<Secp256K1Base>()));
+        }
+        moduli.dedup();
+        assert_eq!(moduli, vec![limb_targets(&order_constant)]);
+
+        // Fields with a different order get their own constant.
+        let scalar_modulus = builder.nonnative_modulus::<Secp256K1Scalar>();
+        assert_ne!(limb_targets(&scalar_modulus), moduli[0]);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_inner_product() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        for len in [1, 2, 5, 8] {
+            let a = (0..len)
+                .map(|_| builder.constant_nonnative(FF::rand()))
+                .collect::<Vec<_>>();
+            // Include `-1` to push the unreduced sum towards its upper bound.
+            let b = (0..len)
+                .map(|i| builder.constant_nonnative(if i == 0 { FF::NEG_ONE } else { FF::rand() }))
+                .collect::<Vec<_>>();
+
+            let inner_product = builder.inner_product_nonnative(&a, &b);
+
+            let mut inner_product_expected = builder.mul_nonnative(&a[0], &b[0]);
+            for (x, y) in a.iter().zip(b.iter()).skip(1) {
+                let product = builder.mul_nonnative(x, y);
+                inner_product_expected = builder.add_nonnative(&inner_product_expected, &product);
+            }
+            builder.connect_nonnative(&inner_product, &inner_product_expected);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_split_to_bits_canonical() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let x_ff = FF::rand();
+
+        let config = CircuitConfig::standard_ecc_config();
+        let pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let x = builder.constant_nonnative(x_ff);
+        let bits = builder.split_nonnative_to_bits_canonical(&x);
+        assert_eq!(bits.len(), FF::BITS);
+
+        let x_bits = x_ff.to_canonical_biguint();
+        for (i, bit) in bits.iter().enumerate() {
+            let bit_expected = builder.constant_bool(x_bits.bit(i as u64));
+            builder.connect(bit.target, bit_expected.target);
+        }
+        for (i, chunk) in bits.chunks(32).enumerate() {
+            let limb = builder.le_sum(chunk.iter());
+            builder.connect(limb, x.value.limbs[i].0);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_nonnative_split_to_bits_canonical_over_modulus() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        // `|FF| + 1` has a valid bit decomposition, but is not a canonical element.
+        let x = builder.add_virtual_nonnative_target::<FF>();
+        pw.set_biguint_target(&x.value, &(FF::order() + BigUint::one()));
+        builder.split_nonnative_to_bits_canonical(&x);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_mul_short_operands() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let mut rng = thread_rng();
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        // Operands narrower than the modulus used to underflow the quotient size.
+        for (a_limbs, b_limbs) in [(1, 1), (2, 3), (5, 5), (1, 8), (8, 8)] {
+            let a_value = rng.gen_biguint(32 * a_limbs as u64) % FF::order();
+            let b_value = rng.gen_biguint(32 * b_limbs as u64) % FF::order();
+
+            let a = builder.add_virtual_nonnative_target_sized::<FF>(a_limbs);
+            let b = builder.add_virtual_nonnative_target_sized::<FF>(b_limbs);
+            pw.set_biguint_target(&a.value, &a_value);
+            pw.set_biguint_target(&b.value, &b_value);
+
+            let product = builder.mul_nonnative(&a, &b);
+            let product_expected =
+                builder.constant_nonnative(FF::from_noncanonical_biguint(a_value * b_value));
+            builder.connect_nonnative(&product, &product_expected);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_inv_fermat() {
+        type FF = Secp256K1Scalar;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let one = builder.constant_nonnative(FF::ONE);
+        for x_ff in [FF::rand(), FF::rand(), FF::ONE, FF::NEG_ONE] {
+            let x = builder.constant_nonnative(x_ff);
+            let inv = builder.inv_nonnative_fermat(&x);
+
+            let inv_hinted = builder.inv_nonnative(&x);
+            builder.connect_nonnative(&inv, &inv_hinted);
+
+            let inv_expected = builder.constant_nonnative(x_ff.inverse());
+            builder.connect_nonnative(&inv, &inv_expected);
+
+            let product = builder.mul_nonnative(&x, &inv);
+            let product = builder.reduce_nonnative(&product);
+            builder.connect_nonnative(&product, &one);
+        }
+
+        // Zero has no inverse and maps to zero.
+        let zero = builder.zero_nonnative::<FF>();
+        let zero_inv = builder.inv_nonnative_fermat(&zero);
+        builder.connect_nonnative(&zero_inv, &zero);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_add_no_reduce_chain() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let x_ff = FF::rand();
+        let chains = [
+            vec![FF::rand(), FF::rand(), FF::NEG_ONE, FF::rand(), FF::NEG_ONE],
+            // `x + (-x)` is exactly `|FF|` before reduction.
+            vec![x_ff, -x_ff, FF::rand()],
+        ];
+        for values in chains {
+            let mut sum = builder.constant_nonnative(values[0]);
+            for &x_ff in values[1..].iter() {
+                let x = builder.constant_nonnative(x_ff);
+                sum = builder.add_nonnative_no_reduce(&sum, &x);
+            }
+            let sum = builder.reduce_nonnative(&sum);
+
+            let sum_expected = builder.constant_nonnative(values.iter().copied().sum::<FF>());
+            builder.connect_nonnative(&sum, &sum_expected);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_assert_reduced() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        // `|FF| - 1` is the largest reduced value.
+        let x = builder.constant_nonnative(FF::NEG_ONE);
+        builder.assert_nonnative_reduced(&x);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "was set twice with different values")]
+    fn test_nonnative_assert_reduced_order() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        // `|FF|` itself is the smallest value that is not reduced.
+        let y = builder.add_virtual_nonnative_target::<FF>();
+        pw.set_biguint_target(&y.value, &FF::order());
+        builder.assert_nonnative_reduced(&y);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_scalar_mul() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let mut rng = thread_rng();
+        let x_ff = FF::rand();
+
+        let config = CircuitConfig::standard_ecc_config();
+        let pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let x = builder.constant_nonnative(x_ff);
+        let scalars = [
+            rng.gen_biguint(FF::BITS as u64),
+            BigUint::from(5u32),
+            BigUint::zero(),
+        ];
+        for scalar in scalars {
+            let scalar_bits = (0..FF::BITS)
+                .map(|i| builder.constant_bool(scalar.bit(i as u64)))
+                .collect::<Vec<_>>();
+            let product = builder.scalar_mul_nonnative(&x, &scalar_bits);
+            let product_expected =
+                builder.constant_nonnative(x_ff * FF::from_noncanonical_biguint(scalar));
+            builder.connect_nonnative(&product, &product_expected);
+        }
+
+        // An empty scalar is zero too.
+        let product = builder.scalar_mul_nonnative(&x, &[]);
+        let zero = builder.zero_nonnative();
+        builder.connect_nonnative(&product, &zero);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_variables_round_trip() {
+        type FF = Secp256K1Base;
+
+        let mut builder = CircuitBuilder::<DefaultParameters, 2>::new();
+
+        let limb_targets =
+            |x: &NonNativeTarget<FF>| x.value.limbs.iter().map(|l| l.0).collect::<Vec<Target>>();
+
+        let x = builder.api.add_virtual_nonnative_target::<FF>();
+        let variables = x.variables();
+        assert_eq!(variables.len(), NonNativeTarget::<FF>::nb_elements());
+        let x_round_trip = NonNativeTarget::<FF>::from_variables_unsafe(&variables);
+        assert_eq!(limb_targets(&x_round_trip), limb_targets(&x));
+
+        // Trailing zero padding is ignored.
+        let zero = builder.zero::<Variable>();
+        let mut padded = variables.clone();
+        padded.extend([zero, zero]);
+        let x_from_padded = NonNativeTarget::<FF>::from_variables_unsafe(&padded);
+        assert_eq!(limb_targets(&x_from_padded), limb_targets(&x));
+
+        // Values with leading zero limbs still produce a full set of elements.
+        for value in [FF::ZERO, FF::ONE, FF::rand()] {
+            let elements = NonNativeTarget::<FF>::elements::<GoldilocksField>(value);
+            assert_eq!(elements.len(), NonNativeTarget::<FF>::nb_elements());
+            assert_eq!(
+                NonNativeTarget::<FF>::from_elements::<GoldilocksField>(&elements),
+                value
+            );
+
+            let mut padded = elements.clone();
+            padded.extend([GoldilocksField::ZERO; 2]);
+            assert_eq!(
+                NonNativeTarget::<FF>::from_elements::<GoldilocksField>(&padded),
+                value
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "nonzero padding element")]
+    fn test_nonnative_from_elements_nonzero_padding() {
+        type FF = Secp256K1Base;
+
+        let mut elements = NonNativeTarget::<FF>::elements::<GoldilocksField>(FF::ONE);
+        elements.push(GoldilocksField::ONE);
+        NonNativeTarget::<FF>::from_elements::<GoldilocksField>(&elements);
+    }
+
+    #[test]
+    fn test_nonnative_from_variables_padding() {
+        type FF = Secp256K1Base;
+
+        let mut builder = CircuitBuilder::<DefaultParameters, 2>::new();
+        let x = builder.read::<NonNativeTarget<FF>>();
+        let padding = builder.read::<Variable>();
+        let mut variables = x.variables();
+        variables.push(padding);
+        let x_checked = NonNativeTarget::<FF>::from_variables(&mut builder, &variables);
+        builder.write(x_checked);
+
+        let circuit = builder.mock_build();
+        let mut input = circuit.input();
+        input.write::<NonNativeTarget<FF>>(FF::rand());
+        input.write::<Variable>(GoldilocksField::ZERO);
+        circuit.mock_prove(&input);
+    }
+
+    #[test]
+    #[should_panic(expected = "was set twice with different values")]
+    fn test_nonnative_from_variables_nonzero_padding() {
+        type FF = Secp256K1Base;
+
+        let mut builder = CircuitBuilder::<DefaultParameters, 2>::new();
+        let x = builder.read::<NonNativeTarget<FF>>();
+        let padding = builder.read::<Variable>();
+        let mut variables = x.variables();
+        variables.push(padding);
+        let x_checked = NonNativeTarget::<FF>::from_variables(&mut builder, &variables);
+        builder.write(x_checked);
+
+        let circuit = builder.mock_build();
+        let mut input = circuit.input();
+        input.write::<NonNativeTarget<FF>>(FF::rand());
+        input.write::<Variable>(GoldilocksField::ONE);
+        circuit.mock_prove(&input);
+    }
+
+    #[test]
+    fn test_nonnative_mul_with_overflow() {
+        type FF = Secp256K1Base;
+
+        let x_ff = FF::rand();
+        let y_ff = FF::rand();
+
+        let mut builder = CircuitBuilder::<DefaultParameters, 2>::new();
+
+        let x = builder.api.constant_nonnative(x_ff);
+        let y = builder.api.constant_nonnative(y_ff);
+        let (product, overflow) = builder.api.mul_nonnative_with_overflow(&x, &y);
+
+        let product_expected = builder.api.constant_nonnative(x_ff * y_ff);
+        builder.api.connect_nonnative(&product, &product_expected);
+
+        let circuit = builder.mock_build();
+        let input = circuit.input();
+        let (witness, _output) = circuit.mock_prove(&input);
+
+        let overflow_expected =
+            (x_ff.to_canonical_biguint() * y_ff.to_canonical_biguint()) / FF::order();
+        assert_eq!(witness.get_biguint_target(overflow), overflow_expected);
+    }
+
+    #[test]
+    fn test_nonnative_sum() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let summands_ff = (0..300).map(|_| FF::rand()).collect::<Vec<_>>();
+        let sum_ff = summands_ff.iter().fold(FF::ZERO, |acc, &x| acc + x);
+
+        let summands = summands_ff
+            .iter()
+            .map(|&x| builder.constant_nonnative(x))
+            .collect::<Vec<_>>();
+        let sum = builder.sum_nonnative(&summands);
+
+        let sum_expected = builder.constant_nonnative(sum_ff);
+        builder.connect_nonnative(&sum, &sum_expected);
+
+        let empty_sum = builder.sum_nonnative::<FF>(&[]);
+        let zero = builder.zero_nonnative();
+        builder.connect_nonnative(&empty_sum, &zero);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_to_canonical() {
+        type FF = Ed25519Scalar;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let x_ff = FF::rand();
+        let x_expected = builder.constant_nonnative(x_ff);
+        for multiple in 0..3u32 {
+            let x = builder.add_virtual_nonnative_target::<FF>();
+            let value = x_ff.to_canonical_biguint() + FF::order() * multiple;
+            pw.set_biguint_target(&x.value, &value);
+
+            let canonical = builder.to_canonical_nonnative(&x, 3);
+            builder.connect_nonnative(&canonical, &x_expected);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_nonnative_to_canonical_exceeds_bound() {
+        type FF = Ed25519Scalar;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let x = builder.add_virtual_nonnative_target::<FF>();
+        let value = FF::rand().to_canonical_biguint() + FF::order() * 2u32;
+        pw.set_biguint_target(&x.value, &value);
+        builder.to_canonical_nonnative(&x, 2);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_op_gate_counts() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let counts = nonnative_op_gate_counts::<F, D, FF>(&config);
+
+        for op in ["add", "sub", "mul", "inv", "reduce"] {
+            assert!(counts.contains_key(op), "missing gate count for {}", op);
+        }
+        assert!(counts["mul"] > counts["add"]);
+    }
+
+    #[test]
+    fn test_nonnative_masked_sum() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let values_ff = (0..6).map(|_| FF::rand()).collect::<Vec<_>>();
+        let mask_values = [true, false, true, true, false, true];
+        let sum_ff = values_ff
+            .iter()
+            .zip(mask_values)
+            .filter(|(_, b)| *b)
+            .fold(FF::ZERO, |acc, (&x, _)| acc + x);
+
+        let values = values_ff
+            .iter()
+            .map(|&x| builder.constant_nonnative(x))
+            .collect::<Vec<_>>();
+        let mask = mask_values
+            .iter()
+            .map(|&b| builder.constant_bool(b))
+            .collect::<Vec<_>>();
+        let sum = builder.masked_sum_nonnative(&values, &mask);
+
+        let sum_expected = builder.constant_nonnative(sum_ff);
+        builder.connect_nonnative(&sum, &sum_expected);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_virtual_target_checked() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let x_ff = FF::rand();
+        let x = builder.add_virtual_nonnative_target_checked::<FF>();
+        pw.set_biguint_target(&x.value, &x_ff.to_canonical_biguint());
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_nonnative_virtual_target_checked_rejects_wide_limb() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let x = builder.add_virtual_nonnative_target_checked::<FF>();
+        pw.set_target(x.value.limbs[0].0, F::from_canonical_u64(1 << 32));
+        for limb in x.value.limbs[1..].iter() {
+            pw.set_target(limb.0, F::ZERO);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_sub_many() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let base_ff = FF::rand();
+        // Include NEG_ONE so the running difference goes negative.
+        let to_subtract_ff = [FF::rand(), FF::NEG_ONE, FF::rand(), FF::ONE, FF::NEG_ONE];
+
+        let base = builder.constant_nonnative(base_ff);
+        let to_subtract = to_subtract_ff
+            .iter()
+            .map(|&x| builder.constant_nonnative(x))
+            .collect::<Vec<_>>();
+        let diff = builder.sub_many_nonnative(&base, &to_subtract);
+
+        let diff_sequential = to_subtract
+            .iter()
+            .fold(base.clone(), |acc, x| builder.sub_nonnative(&acc, x));
+        builder.connect_nonnative(&diff, &diff_sequential);
+
+        let diff_expected =
+            builder.constant_nonnative(to_subtract_ff.iter().fold(base_ff, |acc, &x| acc - x));
+        builder.connect_nonnative(&diff, &diff_expected);
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_generator_ids_per_field() {
+        type L = DefaultParameters;
+        const D: usize = 2;
+        type F = <L as PlonkParameters<D>>::Field;
+
+        assert_ne!(
+            NonNativeMultiplicationGenerator::<F, D, Secp256K1Base>::default().id(),
+            NonNativeMultiplicationGenerator::<F, D, Secp256K1Scalar>::default().id()
+        );
+
+        let mut builder = CircuitBuilder::<L, D>::new();
+
+        let x_base = Secp256K1Base::rand();
+        let y_base = Secp256K1Base::rand();
+        let a = builder.api.constant_nonnative(x_base);
+        let b = builder.api.constant_nonnative(y_base);
+        let product = builder.api.mul_nonnative(&a, &b);
+        let product_expected = builder.api.constant_nonnative(x_base * y_base);
+        builder.api.connect_nonnative(&product, &product_expected);
+
+        let x_scalar = Secp256K1Scalar::rand();
+        let y_scalar = Secp256K1Scalar::rand();
+        let a = builder.api.constant_nonnative(x_scalar);
+        let b = builder.api.constant_nonnative(y_scalar);
+        let product = builder.api.mul_nonnative(&a, &b);
+        let product_expected = builder.api.constant_nonnative(x_scalar * y_scalar);
+        builder.api.connect_nonnative(&product, &product_expected);
+
+        let circuit = builder.build();
+        circuit.test_default_serializers();
+
+        // The deserialized generators must compute over their own fields to satisfy the circuit.
+        let gate_serializer = GateRegistry::<L, D>::new();
+        let hint_serializer = HintRegistry::<L, D>::new();
+        let bytes = circuit
+            .serialize(&gate_serializer, &hint_serializer)
+            .unwrap();
+        let circuit =
+            CircuitBuild::<L, D>::deserialize(&bytes, &gate_serializer, &hint_serializer).unwrap();
+        let input = circuit.input();
+        let (proof, output) = circuit.prove(&input);
+        circuit.verify(&proof, &input, &output);
+    }
+
+    #[test]
+    fn test_nonnative_if() {
+        type FF = Ed25519Scalar;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        let x_ff = FF::rand();
+        let x = builder.constant_nonnative(x_ff);
+        // The other branch is left unreduced; it is passed through as is.
+        let y_value = FF::rand().to_canonical_biguint() + FF::order();
+        let y = builder.constant_biguint(&y_value);
+        let y = builder.biguint_to_nonnative::<FF>(&y);
+
+        for b_value in [true, false] {
+            let b = builder.add_virtual_bool_target_safe();
+            pw.set_bool_target(b, b_value);
+            let selected = builder.if_nonnative(b, &x, &y);
+            let selected_expected = if b_value { &x } else { &y };
+            // `connect_nonnative` may assert both sides are reduced, which `y` is not.
+            builder.connect_biguint(&selected.value, &selected_expected.value);
+        }
+
+        // Compare against selecting via masked terms and `add_nonnative`.
+        let x = builder.add_virtual_nonnative_target::<FF>();
+        let y = builder.add_virtual_nonnative_target::<FF>();
+        let b = builder.add_virtual_bool_target_safe();
+        pw.set_biguint_target(&x.value, &FF::rand().to_canonical_biguint());
+        pw.set_biguint_target(&y.value, &FF::rand().to_canonical_biguint());
+        pw.set_bool_target(b, true);
+
+        let num_gates = builder.num_gates();
+        builder.if_nonnative(b, &x, &y);
+        let if_gates = builder.num_gates() - num_gates;
+
+        let num_gates = builder.num_gates();
+        let not_b = builder.not(b);
+        let maybe_x = builder.mul_nonnative_by_bool(&x, b);
+        let maybe_y = builder.mul_nonnative_by_bool(&y, not_b);
+        builder.add_nonnative(&maybe_x, &maybe_y);
+        let masked_add_gates = builder.num_gates() - num_gates;
+
+        assert!(
+            2 * if_gates <= masked_add_gates,
+            "if_nonnative used {} gates, masked addition used {}",
+            if_gates,
+            masked_add_gates
+        );
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    fn test_nonnative_public_input() {
+        type FF = Secp256K1Base;
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_ecc_config();
+        let mut pw = PartialWitness::new();
+        let mut builder = BaseCircuitBuilder::<F, D>::new(config);
+
+        // Include a small value to exercise the leading-zero limb padding.
+        let values = [FF::rand(), FF::ONE];
+        for value in values {
+            let x = builder.add_virtual_nonnative_target_checked::<FF>();
+            pw.set_biguint_target(&x.value, &value.to_canonical_biguint());
+            builder.register_nonnative_public_input(&x);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+
+        let num_limbs = NonNativeTarget::<FF>::nb_elements();
+        assert_eq!(proof.public_inputs.len(), values.len() * num_limbs);
+        for (i, value) in values.into_iter().enumerate() {
+            let public_inputs = &proof.public_inputs[i * num_limbs..];
+            assert_eq!(read_nonnative_public_input::<F, FF>(public_inputs), value);
+            assert_eq!(
+                public_inputs[..num_limbs],
+                NonNativeTarget::<FF>::elements::<F>(value)[..]
+            );
+        }
+
+        data.verify(proof).unwrap();
+    }
 }
@@ -68,6 +68,18 @@ pub trait CircuitBuilderU32<F: RichField + Extendable<D>, const D: usize> {
     fn u32_to_bits_le(&mut self, num: U32Target) -> [BoolTarget; 32];
 
     fn is_equal_u32(&mut self, x: U32Target, y: U32Target) -> BoolTarget;
+
+    fn and_u32(&mut self, x: U32Target, y: U32Target) -> U32Target;
+
+    fn or_u32(&mut self, x: U32Target, y: U32Target) -> U32Target;
+
+    fn xor_u32(&mut self, x: U32Target, y: U32Target) -> U32Target;
+
+    /// Rotates `x` right by `n` bits, where `n` is taken modulo 32.
+    fn rotate_right_u32(&mut self, x: U32Target, n: u8) -> U32Target;
+
+    /// Logically shifts `x` right by `n` bits.
+    fn shr_u32(&mut self, x: U32Target, n: u8) -> U32Target;
 }
 
 impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderU32<F, D>
@@ -249,6 +261,62 @@ impl<F: RichField + Extendable<D>, const D: usize> CircuitBuilderU32<F, D>
     fn is_equal_u32(&mut self, x: U32Target, y: U32Target) -> BoolTarget {
         self.is_equal(x.0, y.0)
     }
+
+    // The bitwise operations below decompose both operands into bits, which also range-checks
+    // them, and repack the combined bits. The result is therefore always a valid u32.
+    fn and_u32(&mut self, x: U32Target, y: U32Target) -> U32Target {
+        let x_bits = self.u32_to_bits_le(x);
+        let y_bits = self.u32_to_bits_le(y);
+        let bits = x_bits
+            .iter()
+            .zip(y_bits.iter())
+            .map(|(&a, &b)| self.and(a, b))
+            .collect::<Vec<_>>();
+        U32Target(self.le_sum(bits.iter()))
+    }
+
+    fn or_u32(&mut self, x: U32Target, y: U32Target) -> U32Target {
+        let x_bits = self.u32_to_bits_le(x);
+        let y_bits = self.u32_to_bits_le(y);
+        let bits = x_bits
+            .iter()
+            .zip(y_bits.iter())
+            .map(|(&a, &b)| self.or(a, b))
+            .collect::<Vec<_>>();
+        U32Target(self.le_sum(bits.iter()))
+    }
+
+    fn xor_u32(&mut self, x: U32Target, y: U32Target) -> U32Target {
+        let x_bits = self.u32_to_bits_le(x);
+        let y_bits = self.u32_to_bits_le(y);
+        let bits = x_bits
+            .iter()
+            .zip(y_bits.iter())
+            .map(|(&a, &b)| {
+                // a ^ b = a + b - 2ab
+                let sum = self.add(a.target, b.target);
+                let xor = self.arithmetic(-F::TWO, F::ONE, a.target, b.target, sum);
+                BoolTarget::new_unsafe(xor)
+            })
+            .collect::<Vec<_>>();
+        U32Target(self.le_sum(bits.iter()))
+    }
+
+    fn rotate_right_u32(&mut self, x: U32Target, n: u8) -> U32Target {
+        let n = (n % 32) as usize;
+        let x_bits = self.u32_to_bits_le(x);
+        let bits = (0..32).map(|i| x_bits[(i + n) % 32]).collect::<Vec<_>>();
+        U32Target(self.le_sum(bits.iter()))
+    }
+
+    fn shr_u32(&mut self, x: U32Target, n: u8) -> U32Target {
+        let n = n as usize;
+        if n >= 32 {
+            return self.zero_u32();
+        }
+        let x_bits = self.u32_to_bits_le(x);
+        U32Target(self.le_sum(x_bits[n..].iter()))
+    }
 }
 
 #[derive(Debug)]
@@ -315,6 +383,70 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    pub fn test_bitwise_u32() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_recursion_config();
+
+        let pw = PartialWitness::new();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+
+        let mut rng = OsRng;
+        for _ in 0..8 {
+            let x: u32 = rng.gen();
+            let y: u32 = rng.gen();
+            let x_target = builder.constant_u32(x);
+            let y_target = builder.constant_u32(y);
+
+            let and = builder.and_u32(x_target, y_target);
+            let or = builder.or_u32(x_target, y_target);
+            let xor = builder.xor_u32(x_target, y_target);
+
+            let expected_and = builder.constant_u32(x & y);
+            let expected_or = builder.constant_u32(x | y);
+            let expected_xor = builder.constant_u32(x ^ y);
+            builder.connect_u32(and, expected_and);
+            builder.connect_u32(or, expected_or);
+            builder.connect_u32(xor, expected_xor);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
+    #[test]
+    pub fn test_rotate_and_shift_u32() {
+        const D: usize = 2;
+        type C = PoseidonGoldilocksConfig;
+        type F = <C as GenericConfig<D>>::F;
+
+        let config = CircuitConfig::standard_recursion_config();
+
+        let pw = PartialWitness::new();
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+
+        let mut rng = OsRng;
+        let x: u32 = rng.gen();
+        let x_target = builder.constant_u32(x);
+        for n in [0u8, 2, 7, 13, 22, 31, 32, 40] {
+            let rotated = builder.rotate_right_u32(x_target, n);
+            let expected_rotated = builder.constant_u32(x.rotate_right(n as u32));
+            builder.connect_u32(rotated, expected_rotated);
+
+            let shifted = builder.shr_u32(x_target, n);
+            let expected_shifted = builder.constant_u32(x.checked_shr(n as u32).unwrap_or(0));
+            builder.connect_u32(shifted, expected_shifted);
+        }
+
+        let data = builder.build::<C>();
+        let proof = data.prove(pw).unwrap();
+        data.verify(proof).unwrap();
+    }
+
     #[test]
     pub fn test_add_many_u32s() {
         const D: usize = 2;